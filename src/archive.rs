@@ -0,0 +1,72 @@
+use crate::{ArchivedByteUnit, ByteUnit};
+
+impl ArchivedByteUnit {
+    /// Returns the number of bytes in the archived `ByteUnit` as a `u64`
+    /// without deserializing it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "alloc")] {
+    /// use ubyte::{ArchivedByteUnit, ToByteUnit};
+    ///
+    /// let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&512.kibibytes()).unwrap();
+    /// let archived = rkyv::access::<ArchivedByteUnit, rkyv::rancor::Error>(&bytes).unwrap();
+    /// assert_eq!(archived.as_u64(), 524288);
+    /// assert_eq!(archived.as_byte_unit(), 512.kibibytes());
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub const fn as_u64(&self) -> u64 {
+        self.0.to_native()
+    }
+
+    /// Returns the archived `ByteUnit` without deserializing it.
+    #[inline(always)]
+    pub const fn as_byte_unit(&self) -> ByteUnit {
+        ByteUnit(self.as_u64())
+    }
+}
+
+impl From<&ArchivedByteUnit> for ByteUnit {
+    #[inline(always)]
+    fn from(v: &ArchivedByteUnit) -> Self {
+        v.as_byte_unit()
+    }
+}
+
+impl PartialEq<ByteUnit> for ArchivedByteUnit {
+    #[inline(always)]
+    fn eq(&self, other: &ByteUnit) -> bool {
+        self.as_u64() == other.as_u64()
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod rkyv_tests {
+    use rkyv::rancor::Error;
+
+    use crate::{ArchivedByteUnit, ByteUnit, ToByteUnit};
+
+    #[test]
+    fn test_rkyv() {
+        assert_eq!(core::mem::size_of::<ArchivedByteUnit>(), 8);
+
+        for value in [0.bytes(), 512.kibibytes(), 7.gigabytes(), ByteUnit::max_value()] {
+            let bytes = rkyv::to_bytes::<Error>(&value).unwrap();
+            assert_eq!(&bytes[..], &value.as_u64().to_le_bytes()[..]);
+
+            let archived = rkyv::access::<ArchivedByteUnit, Error>(&bytes).unwrap();
+            assert_eq!(archived.as_u64(), value.as_u64());
+            assert_eq!(*archived, value);
+            assert_eq!(ByteUnit::from(archived), value);
+            assert_eq!(rkyv::deserialize::<ByteUnit, Error>(archived).unwrap(), value);
+        }
+
+        let sizes = [1.kibibytes(), 2.mebibytes()];
+        let bytes = rkyv::to_bytes::<Error>(&sizes).unwrap();
+        let archived = rkyv::access::<[ArchivedByteUnit; 2], Error>(&bytes).unwrap();
+        assert!(archived[0] < archived[1]);
+        assert_eq!(archived[1], 2.mebibytes());
+    }
+}
@@ -1,169 +1,880 @@
 use core::cmp::Ordering;
+use core::iter::Sum;
 use core::ops::{Add, Sub, Mul, Div, Rem, Shl, Shr};
 use core::ops::{AddAssign, SubAssign, MulAssign, DivAssign, RemAssign, ShlAssign, ShrAssign};
 
-use crate::ByteUnit;
+use crate::{ByteUnit, BitUnit};
 
-impl<T: Into<ByteUnit>> Add<T> for ByteUnit {
-    type Output = Self;
+macro_rules! impl_self_assign_op {
+    ($U:ident, $Trait:ident, $func:ident, $op:tt) => (
+        impl<T: Into<$U>> $Trait<T> for $U {
+            #[inline(always)]
+            fn $func(&mut self, rhs: T) {
+                *self = *self $op rhs.into();
+            }
+        }
+    )
+}
 
-    #[inline(always)]
-    fn add(self, rhs: T) -> Self::Output {
-        ByteUnit(self.0.saturating_add(rhs.into().0))
-    }
+macro_rules! impl_arith_op_on_core_type {
+    ($U:ident, $T:ident, $Trait:ident, $func:ident, $op:tt) => (
+        impl $Trait<$U> for $T {
+            type Output = $U;
+
+            #[inline(always)]
+            fn $func(self, rhs: $U) -> Self::Output {
+                $U::from(self) $op rhs
+            }
+        }
+    )
 }
 
-impl<T: Into<ByteUnit>> Sub<T> for ByteUnit {
-    type Output = Self;
+macro_rules! impl_arith_ops_on_core {
+    ($U:ident, $T:ident) => (
+        impl_arith_op_on_core_type!($U, $T, Add, add, +);
+        impl_arith_op_on_core_type!($U, $T, Sub, sub, -);
+        impl_arith_op_on_core_type!($U, $T, Mul, mul, *);
+        impl_arith_op_on_core_type!($U, $T, Div, div, /);
+        impl_arith_op_on_core_type!($U, $T, Rem, rem, %);
+        impl_arith_op_on_core_type!($U, $T, Shl, shl, <<);
+        impl_arith_op_on_core_type!($U, $T, Shr, shr, >>);
+
+        impl PartialEq<$U> for $T {
+            #[inline(always)]
+            fn eq(&self, other: &$U) -> bool {
+                $U::from(*self).eq(other)
+            }
+        }
+
+        impl PartialOrd<$U> for $T {
+            #[inline(always)]
+            fn partial_cmp(&self, other: &$U) -> Option<Ordering> {
+                $U::from(*self).partial_cmp(other)
+            }
+        }
+    )
+}
+
+macro_rules! impl_arith_ops {
+    ($U:ident) => (
+        impl<T: Into<$U>> Add<T> for $U {
+            type Output = Self;
+
+            #[inline(always)]
+            fn add(self, rhs: T) -> Self::Output {
+                $U(self.0.saturating_add(rhs.into().0))
+            }
+        }
+
+        impl<T: Into<$U>> Sub<T> for $U {
+            type Output = Self;
 
+            #[inline(always)]
+            fn sub(self, rhs: T) -> Self::Output {
+                $U(self.0.saturating_sub(rhs.into().0))
+            }
+        }
+
+        impl<T: Into<$U>> Mul<T> for $U {
+            type Output = Self;
+
+            #[inline(always)]
+            fn mul(self, rhs: T) -> Self::Output {
+                $U(self.0.saturating_mul(rhs.into().0))
+            }
+        }
+
+        impl<T: Into<$U>> Div<T> for $U {
+            type Output = Self;
+
+            #[inline(always)]
+            fn div(self, rhs: T) -> Self::Output {
+                let value = rhs.into().0;
+                match value {
+                    0 => $U::max_value(),
+                    _ => $U(self.0 / value)
+                }
+            }
+        }
+
+        impl<T: Into<$U>> Rem<T> for $U {
+            type Output = Self;
+
+            #[inline(always)]
+            fn rem(self, rhs: T) -> Self::Output {
+                let value = rhs.into().0;
+                match value {
+                    0 => $U(0),
+                    _ => $U(self.0 % value)
+                }
+            }
+        }
+
+        /// Shifts left by the _value_ of `rhs` as a number of bits: `x << 2` is
+        /// `x * 4`. Saturates to `max_value()` if a set bit would be shifted
+        /// out, as any would be if `rhs` is 64 or more. Zero stays zero.
+        impl<T: Into<$U>> Shl<T> for $U {
+            type Output = Self;
+            fn shl(self, rhs: T) -> Self::Output {
+                let wanted = rhs.into().0;
+                let available = self.0.leading_zeros() as u64;
+                if self.0 == 0 {
+                    self
+                } else if wanted > available || wanted >= 64 {
+                    $U::max_value()
+                } else {
+                    $U(self.0 << wanted)
+                }
+            }
+        }
+
+        /// Shifts right by the _value_ of `rhs` as a number of bits: `x >> 2`
+        /// is `x / 4`. Yields `0` if `rhs` is 64 or more.
+        impl<T: Into<$U>> Shr<T> for $U {
+            type Output = Self;
+            fn shr(self, rhs: T) -> Self::Output {
+                match rhs.into().0 {
+                    n if n >= 64 => $U(0),
+                    n => $U(self.0 >> n),
+                }
+            }
+        }
+
+        impl<T: Into<$U> + Copy> PartialEq<T> for $U {
+            fn eq(&self, other: &T) -> bool {
+                self.0 == (*other).into().0
+            }
+        }
+
+        impl<T: Into<$U> + Copy> PartialOrd<T> for $U {
+            fn partial_cmp(&self, other: &T) -> Option<Ordering> {
+                self.0.partial_cmp(&(*other).into().0)
+            }
+        }
+
+        impl Sum<$U> for $U {
+            #[inline(always)]
+            fn sum<I: Iterator<Item = $U>>(iter: I) -> Self {
+                iter.fold($U(0), |a, b| a + b)
+            }
+        }
+
+        impl<'a> Sum<&'a $U> for $U {
+            #[inline(always)]
+            fn sum<I: Iterator<Item = &'a $U>>(iter: I) -> Self {
+                iter.copied().sum()
+            }
+        }
+
+        impl_self_assign_op!($U, AddAssign, add_assign, +);
+        impl_self_assign_op!($U, SubAssign, sub_assign, -);
+        impl_self_assign_op!($U, MulAssign, mul_assign, *);
+        impl_self_assign_op!($U, DivAssign, div_assign, /);
+        impl_self_assign_op!($U, RemAssign, rem_assign, %);
+        impl_self_assign_op!($U, ShrAssign, shr_assign, >>);
+        impl_self_assign_op!($U, ShlAssign, shl_assign, <<);
+
+        impl_arith_ops_on_core!($U, usize);
+        impl_arith_ops_on_core!($U, u8);
+        impl_arith_ops_on_core!($U, u16);
+        impl_arith_ops_on_core!($U, u32);
+        impl_arith_ops_on_core!($U, u64);
+        impl_arith_ops_on_core!($U, u128);
+
+        impl_arith_ops_on_core!($U, isize);
+        impl_arith_ops_on_core!($U, i8);
+        impl_arith_ops_on_core!($U, i16);
+        impl_arith_ops_on_core!($U, i32);
+        impl_arith_ops_on_core!($U, i64);
+        impl_arith_ops_on_core!($U, i128);
+    )
+}
+
+impl_arith_ops!(ByteUnit);
+impl_arith_ops!(BitUnit);
+
+macro_rules! checked_fn {
+    ($name:ident, $what:expr, $example:expr) => (
+        #[doc = concat!("Returns `self ", $what, " rhs`, or `None` if the operation overflows,")]
+        /// underflows, or divides by zero. This is exactly the standard library's
+        #[doc = concat!("[`u64::", stringify!($name), "()`] on byte counts.")]
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use ubyte::{ByteUnit, ToByteUnit};
+        ///
+        #[doc = $example]
+        /// ```
+        #[inline(always)]
+        pub fn $name<T: Into<ByteUnit>>(self, rhs: T) -> Option<ByteUnit> {
+            self.0.$name(rhs.into().0).map(ByteUnit)
+        }
+    )
+}
+
+macro_rules! overflowing_fn {
+    ($name:ident, $what:expr, $example:expr) => (
+        #[doc = concat!("Returns `self ", $what, " rhs` along with whether the operation")]
+        /// overflowed. On overflow, the wrapped value is returned. This is exactly
+        /// the standard library's
+        #[doc = concat!("[`u64::", stringify!($name), "()`] on byte counts.")]
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use ubyte::{ByteUnit, ToByteUnit};
+        ///
+        #[doc = $example]
+        /// ```
+        #[inline(always)]
+        pub fn $name<T: Into<ByteUnit>>(self, rhs: T) -> (ByteUnit, bool) {
+            let (value, overflowed) = self.0.$name(rhs.into().0);
+            (ByteUnit(value), overflowed)
+        }
+    )
+}
+
+macro_rules! wrapping_fn {
+    ($name:ident, $what:expr, $example:expr) => (
+        #[doc = concat!("Returns `self ", $what, " rhs`, wrapping around at the boundaries of")]
+        /// a `u64`. This is exactly the standard library's
+        #[doc = concat!("[`u64::", stringify!($name), "()`] on byte counts.")]
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use ubyte::{ByteUnit, ToByteUnit};
+        ///
+        #[doc = $example]
+        /// ```
+        #[inline(always)]
+        pub fn $name<T: Into<ByteUnit>>(self, rhs: T) -> ByteUnit {
+            ByteUnit(self.0.$name(rhs.into().0))
+        }
+    )
+}
+
+macro_rules! saturating_fn {
+    ($name:ident, $Op:ident::$op:ident, $what:expr, $example:expr) => (
+        #[doc = concat!("Returns `self ", $what, " rhs` exactly as the `", $what, "` operator")]
+        /// does, saturating at `0` and at [`ByteUnit::max_value()`], but named so
+        /// that the saturation is explicit at the call site.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use ubyte::{ByteUnit, ToByteUnit};
+        ///
+        #[doc = $example]
+        /// ```
+        #[inline(always)]
+        pub fn $name<T: Into<ByteUnit>>(self, rhs: T) -> ByteUnit {
+            $Op::$op(self, rhs)
+        }
+    )
+}
+
+impl ByteUnit {
+    checked_fn!(checked_add, "+", "\
+        assert_eq!(1.kibibytes().checked_add(1024), Some(2.kibibytes()));\n\
+        assert_eq!(ByteUnit::max_value().checked_add(1), None);");
+
+    checked_fn!(checked_sub, "-", "\
+        assert_eq!(1.kibibytes().checked_sub(24), Some(1.kilobytes()));\n\
+        assert_eq!(1.kilobytes().checked_sub(1.kibibytes()), None);");
+
+    checked_fn!(checked_mul, "*", "\
+        assert_eq!(1.kibibytes().checked_mul(1024), Some(1.mebibytes()));\n\
+        assert_eq!(16.exbibytes().checked_mul(2), None);");
+
+    checked_fn!(checked_div, "/", "\
+        assert_eq!(1.mebibytes().checked_div(1024), Some(1.kibibytes()));\n\
+        assert_eq!(1.mebibytes().checked_div(0), None);");
+
+    overflowing_fn!(overflowing_add, "+", "\
+        assert_eq!(1.kibibytes().overflowing_add(1024), (2.kibibytes(), false));\n\
+        assert_eq!(ByteUnit::max_value().overflowing_add(2), (1.bytes(), true));");
+
+    overflowing_fn!(overflowing_sub, "-", "\
+        assert_eq!(1.kibibytes().overflowing_sub(24), (1.kilobytes(), false));\n\
+        assert_eq!(0.bytes().overflowing_sub(1), (ByteUnit::max_value(), true));");
+
+    overflowing_fn!(overflowing_mul, "*", "\
+        assert_eq!(1.kibibytes().overflowing_mul(1024), (1.mebibytes(), false));\n\
+        assert_eq!(8.exbibytes().overflowing_mul(3), (8.exbibytes(), true));");
+
+    wrapping_fn!(wrapping_add, "+", "\
+        assert_eq!(1.kibibytes().wrapping_add(1024), 2.kibibytes());\n\
+        assert_eq!(ByteUnit::max_value().wrapping_add(2), 1.bytes());");
+
+    wrapping_fn!(wrapping_sub, "-", "\
+        assert_eq!(1.kibibytes().wrapping_sub(24), 1.kilobytes());\n\
+        assert_eq!(0.bytes().wrapping_sub(1), ByteUnit::max_value());");
+
+    wrapping_fn!(wrapping_mul, "*", "\
+        assert_eq!(1.kibibytes().wrapping_mul(1024), 1.mebibytes());\n\
+        assert_eq!(8.exbibytes().wrapping_mul(3), 8.exbibytes());");
+
+    saturating_fn!(saturating_add, Add::add, "+", "\
+        assert_eq!(1.kibibytes().saturating_add(1024), 2.kibibytes());\n\
+        assert_eq!(ByteUnit::max_value().saturating_add(1), ByteUnit::max_value());");
+
+    saturating_fn!(saturating_sub, Sub::sub, "-", "\
+        assert_eq!(1.kibibytes().saturating_sub(24), 1.kilobytes());\n\
+        assert_eq!(1.kilobytes().saturating_sub(1.kibibytes()), 0);");
+
+    saturating_fn!(saturating_mul, Mul::mul, "*", "\
+        assert_eq!(1.kibibytes().saturating_mul(1024), 1.mebibytes());\n\
+        assert_eq!(16.exbibytes().saturating_mul(2), ByteUnit::max_value());");
+
+    saturating_fn!(saturating_div, Div::div, "/", "\
+        assert_eq!(1.mebibytes().saturating_div(1024), 1.kibibytes());\n\
+        // Dividing by zero saturates rather than panicking.\n\
+        assert_eq!(1.mebibytes().saturating_div(0), ByteUnit::max_value());");
+
+    /// Returns the saturating sum of every item in `iter`, each of which may
+    /// be a `ByteUnit` or any other type convertible into one, such as an
+    /// integer number of bytes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteUnit, ToByteUnit};
+    ///
+    /// let file_sizes: [u64; 3] = [512, 1024, 2560];
+    /// assert_eq!(ByteUnit::total(file_sizes), 4.kibibytes());
+    /// assert_eq!(ByteUnit::total([1.kilobytes(), 24.bytes()]), 1.kibibytes());
+    /// assert_eq!(ByteUnit::total(Vec::<ByteUnit>::new()), 0);
+    ///
+    /// let huge = [ByteUnit::max_value(), 1.bytes()];
+    /// assert_eq!(ByteUnit::total(huge), ByteUnit::max_value());
+    /// ```
+    pub fn total<T: Into<ByteUnit>, I: IntoIterator<Item = T>>(iter: I) -> ByteUnit {
+        iter.into_iter().fold(ByteUnit(0), |total, item| total + item.into())
+    }
+
+    /// Returns the absolute difference between `self` and `other`: `|self -
+    /// other|`. Unlike `self - other`, which saturates at `0` when `other` is
+    /// larger, the result is independent of the order of the operands.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteUnit, ToByteUnit};
+    ///
+    /// assert_eq!(1.kibibytes().abs_diff(1.kilobytes()), 24.bytes());
+    /// assert_eq!(1.kilobytes().abs_diff(1.kibibytes()), 24.bytes());
+    /// assert_eq!(1.kilobytes() - 1.kibibytes(), 0);
+    /// ```
     #[inline(always)]
-    fn sub(self, rhs: T) -> Self::Output {
-        ByteUnit(self.0.saturating_sub(rhs.into().0))
+    pub fn abs_diff<T: Into<ByteUnit>>(self, other: T) -> ByteUnit {
+        let other = other.into();
+        core::cmp::max(self, other) - core::cmp::min(self, other)
     }
-}
 
-impl<T: Into<ByteUnit>> Mul<T> for ByteUnit {
-    type Output = Self;
+    /// Returns `true` if `self` and `other` differ by at most `tolerance`:
+    /// `self.abs_diff(other) <= tolerance`. A zero `tolerance` is exact
+    /// equality.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteUnit, FloatToByteUnit, ToByteUnit};
+    ///
+    /// let value = 1.5.gibibytes();
+    /// let round_trip = (value.to_unit(ByteUnit::MB) * 1e6).bytes();
+    /// assert!(round_trip.eq_approx(value, 1.bytes()));
+    ///
+    /// assert!(1.kilobytes().eq_approx(1.kibibytes(), 24.bytes()));
+    /// assert!(!1.kilobytes().eq_approx(1.kibibytes(), 23.bytes()));
+    /// assert!(1.kilobytes().eq_approx(1000, 0.bytes()));
+    /// ```
+    #[inline(always)]
+    pub fn eq_approx<T: Into<ByteUnit>>(self, other: T, tolerance: ByteUnit) -> bool {
+        self.abs_diff(other) <= tolerance
+    }
 
+    /// Returns how far `rhs` exceeds `self`: `rhs - self` if `rhs` is larger
+    /// and `0` otherwise.
+    ///
+    /// This is the complement of the saturating `self - rhs`: exactly one of
+    /// the two is non-zero unless `self == rhs`, in which case both are. Use
+    /// [`ByteUnit::checked_sub()`] to distinguish being exactly at a limit
+    /// from exceeding it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::ToByteUnit;
+    ///
+    /// let budget = 1.gibibytes();
+    /// let used = budget + 200.mebibytes();
+    /// assert_eq!(budget - used, 0);
+    /// assert_eq!(budget.checked_sub(used), None);
+    /// assert_eq!(budget.overshoot(used), 200.mebibytes());
+    ///
+    /// assert_eq!(budget.overshoot(budget), 0);
+    /// assert_eq!(budget.checked_sub(budget), Some(0.bytes()));
+    /// assert_eq!(budget.overshoot(1.mebibytes()), 0);
+    /// ```
     #[inline(always)]
-    fn mul(self, rhs: T) -> Self::Output {
-        ByteUnit(self.0.saturating_mul(rhs.into().0))
+    pub fn overshoot<T: Into<ByteUnit>>(self, rhs: T) -> ByteUnit {
+        rhs.into() - self
     }
-}
 
-impl<T: Into<ByteUnit>> Div<T> for ByteUnit {
-    type Output = Self;
+    /// Returns the number of whole times `other` fits into `self` as a
+    /// dimensionless count: `self / other`, rounded toward zero.
+    ///
+    /// This is the integer division performed by `Div`, but typed as the
+    /// `u64` count it is rather than as a `ByteUnit`. As with `Div`, division
+    /// by zero saturates: the result is `u64::MAX`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteUnit, ToByteUnit};
+    ///
+    /// let chunks: u64 = 10.mebibytes().div_count(4.kibibytes());
+    /// assert_eq!(chunks, 2560);
+    /// assert_eq!(3.kilobytes().div_count(2.kilobytes()), 1);
+    /// assert_eq!(1.kilobytes().div_count(2.kilobytes()), 0);
+    /// assert_eq!(1.kibibytes().div_count(ByteUnit::B), 1024);
+    ///
+    /// assert_eq!(1.bytes().div_count(0), u64::MAX);
+    /// assert_eq!(0.bytes().div_count(0), u64::MAX);
+    /// ```
+    #[inline(always)]
+    pub fn div_count<T: Into<ByteUnit>>(self, other: T) -> u64 {
+        self.0.checked_div(other.into().0).unwrap_or(u64::MAX)
+    }
 
+    /// Returns the number of `chunk`s needed to hold `self`: `self / chunk`,
+    /// rounded up.
+    ///
+    /// This is [`ByteUnit::div_count()`] rounded away from zero, as needed to
+    /// count the blocks or pages that hold a payload. As with `div_count()`,
+    /// division by zero saturates: a zero `chunk` yields `u64::MAX`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::ToByteUnit;
+    ///
+    /// assert_eq!(5.kibibytes().div_ceil_count(4.kibibytes()), 2);
+    /// assert_eq!(8.kibibytes().div_ceil_count(4.kibibytes()), 2);
+    /// assert_eq!(1.bytes().div_ceil_count(4.kibibytes()), 1);
+    /// assert_eq!(0.bytes().div_ceil_count(4.kibibytes()), 0);
+    ///
+    /// assert_eq!(1.bytes().div_ceil_count(0), u64::MAX);
+    /// ```
     #[inline(always)]
-    fn div(self, rhs: T) -> Self::Output {
-        let value = rhs.into().0;
-        match value {
-            0 => ByteUnit::max_value(),
-            _ => ByteUnit(self.0 / value)
+    pub fn div_ceil_count<T: Into<ByteUnit>>(self, chunk: T) -> u64 {
+        match chunk.into().0 {
+            0 => u64::MAX,
+            chunk => self.0.div_ceil(chunk),
         }
     }
-}
 
-impl<T: Into<ByteUnit>> Rem<T> for ByteUnit {
-    type Output = Self;
+    /// Returns the midpoint of `self` and `other`: `(self + other) / 2`,
+    /// rounded down.
+    ///
+    /// Unlike `(a + b) / 2`, which saturates the sum at
+    /// [`ByteUnit::max_value()`] and thus yields the wrong midpoint for large
+    /// values, the midpoint is computed without intermediate overflow. This is
+    /// exactly the standard library's [`u64::midpoint()`] on byte counts.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteUnit, ToByteUnit};
+    ///
+    /// assert_eq!(1.kibibytes().midpoint(3.kibibytes()), 2.kibibytes());
+    /// assert_eq!(3.bytes().midpoint(0), 1.bytes());
+    /// assert_eq!(0.bytes().midpoint(3), 1.bytes());
+    ///
+    /// let max = ByteUnit::max_value();
+    /// assert_eq!(max.midpoint(max), max);
+    /// assert_eq!(max.midpoint(max - 2), max - 1);
+    /// ```
+    #[inline(always)]
+    pub fn midpoint<T: Into<ByteUnit>>(self, other: T) -> ByteUnit {
+        let (a, b) = (self.0, other.into().0);
+        ByteUnit((a & b) + ((a ^ b) >> 1))
+    }
 
+    /// Adds the signed `delta` bytes to `self`, saturating at `0` when the
+    /// result would be negative and at [`ByteUnit::max_value()`] when it would
+    /// overflow. This is exactly the standard library's
+    /// [`u64::saturating_add_signed()`] on byte counts.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteUnit, ToByteUnit};
+    ///
+    /// let mut total = 1.kibibytes();
+    /// total = total.saturating_add_signed(512);
+    /// total = total.saturating_add_signed(-256);
+    /// assert_eq!(total, 1280.bytes());
+    ///
+    /// assert_eq!(1.kibibytes().saturating_add_signed(-2048), 0);
+    /// assert_eq!(ByteUnit::max_value().saturating_add_signed(1), ByteUnit::max_value());
+    /// ```
     #[inline(always)]
-    fn rem(self, rhs: T) -> Self::Output {
-        let value = rhs.into().0;
-        match value {
-            0 => ByteUnit(0),
-            _ => ByteUnit(self.0 % value)
+    pub const fn saturating_add_signed(self, delta: i64) -> ByteUnit {
+        ByteUnit(self.0.saturating_add_signed(delta))
+    }
+
+    /// Returns the greatest common divisor of `self` and `other`: the largest
+    /// size both are a multiple of. The GCD of `0` and `x` is `x`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteUnit, ToByteUnit};
+    ///
+    /// assert_eq!(4.kibibytes().gcd(6.kibibytes()), 2.kibibytes());
+    /// assert_eq!(512.bytes().gcd(4.kibibytes()), 512.bytes());
+    /// assert_eq!(1.kilobytes().gcd(1.kibibytes()), 8.bytes());
+    /// assert_eq!(0.bytes().gcd(4.kibibytes()), 4.kibibytes());
+    /// ```
+    #[inline]
+    pub fn gcd<T: Into<ByteUnit>>(self, other: T) -> ByteUnit {
+        let (mut a, mut b) = (self.0, other.into().0);
+        while b != 0 {
+            let r = a % b;
+            a = b;
+            b = r;
         }
+
+        ByteUnit(a)
     }
-}
 
-impl<T: Into<ByteUnit>> Shl<T> for ByteUnit {
-    type Output = Self;
-    fn shl(self, rhs: T) -> Self::Output {
-        let wanted = rhs.into().0;
-        let available = self.0.leading_zeros() as u64;
-        if wanted > available {
-            ByteUnit::max_value()
-        } else {
-            ByteUnit(self.0 << wanted)
+    /// Returns the least common multiple of `self` and `other`: the smallest
+    /// size that is a multiple of both, saturating at
+    /// [`ByteUnit::max_value()`]. The LCM of `0` and `x` is `0`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteUnit, ToByteUnit};
+    ///
+    /// assert_eq!(512.bytes().lcm(4.kibibytes()), 4.kibibytes());
+    /// assert_eq!(4.kibibytes().lcm(6.kibibytes()), 12.kibibytes());
+    /// assert_eq!(1.kilobytes().lcm(1.kibibytes()), 128.kilobytes());
+    /// assert_eq!(0.bytes().lcm(4.kibibytes()), 0);
+    ///
+    /// let max = ByteUnit::max_value();
+    /// assert_eq!(max.lcm(max - 1), max);
+    /// ```
+    #[inline]
+    pub fn lcm<T: Into<ByteUnit>>(self, other: T) -> ByteUnit {
+        let other = other.into();
+        match self.gcd(other).0 {
+            0 => ByteUnit(0),
+            gcd => ByteUnit((self.0 / gcd).saturating_mul(other.0)),
         }
     }
-}
 
-impl<T: Into<ByteUnit>> Shr<T> for ByteUnit {
-    type Output = Self;
-    fn shr(self, rhs: T) -> Self::Output {
-        ByteUnit(self.0 >> rhs.into().0)
+    /// Returns `self` doubled, saturating at [`ByteUnit::max_value()`].
+    ///
+    /// This is `self << 1` spelled for growth schedules: repeatedly doubling
+    /// a buffer size eventually settles at `max_value()`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteUnit, ToByteUnit};
+    ///
+    /// let mut buf = 4.kibibytes();
+    /// for _ in 0..3 {
+    ///     buf = buf.double();
+    /// }
+    ///
+    /// assert_eq!(buf, 32.kibibytes());
+    /// assert_eq!(0.bytes().double(), 0);
+    /// assert_eq!(ByteUnit::max_value().double(), ByteUnit::max_value());
+    /// ```
+    #[inline(always)]
+    pub const fn double(self) -> ByteUnit {
+        ByteUnit(self.0.saturating_mul(2))
     }
-}
 
-impl<T: Into<ByteUnit> + Copy> PartialEq<T> for ByteUnit {
-    fn eq(&self, other: &T) -> bool {
-        self.0 == (*other).into().0
+    /// Returns `self` multiplied by `factor`, saturating at
+    /// [`ByteUnit::max_value()`].
+    ///
+    /// This is `self * factor` spelled for growth schedules, and is `const`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteUnit, ToByteUnit};
+    ///
+    /// assert_eq!(4.kibibytes().grow_by_factor(3), 12.kibibytes());
+    /// assert_eq!(4.kibibytes().grow_by_factor(1), 4.kibibytes());
+    /// assert_eq!(4.kibibytes().grow_by_factor(0), 0);
+    /// assert_eq!(ByteUnit::EiB.grow_by_factor(16), ByteUnit::max_value());
+    /// ```
+    #[inline(always)]
+    pub const fn grow_by_factor(self, factor: u32) -> ByteUnit {
+        ByteUnit(self.0.saturating_mul(factor as u64))
+    }
+
+    /// Returns `self * num / den`, rounded toward zero and saturating at
+    /// [`ByteUnit::max_value()`], without using floating point.
+    ///
+    /// The product is computed exactly in a `u128`, so it never overflows
+    /// before the division. As with `Div`, a zero `den` saturates: the result
+    /// is `max_value()`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteUnit, ToByteUnit};
+    ///
+    /// assert_eq!(4.kibibytes().mul_ratio(3, 4), 3.kibibytes());
+    /// assert_eq!(10.bytes().mul_ratio(1, 3), 3.bytes());
+    /// assert_eq!(ByteUnit::max_value().mul_ratio(3, 4), u64::MAX / 4 * 3 + 2);
+    /// assert_eq!(ByteUnit::max_value().mul_ratio(5, 4), ByteUnit::max_value());
+    /// assert_eq!(1.bytes().mul_ratio(1, 0), ByteUnit::max_value());
+    /// ```
+    #[inline(always)]
+    pub const fn mul_ratio(self, num: u64, den: u64) -> ByteUnit {
+        if den == 0 {
+            return ByteUnit::max_value();
+        }
+
+        match self.0 as u128 * num as u128 / den as u128 {
+            value if value > u64::MAX as u128 => ByteUnit::max_value(),
+            value => ByteUnit(value as u64),
+        }
     }
-}
 
-impl<T: Into<ByteUnit> + Copy> PartialOrd<T> for ByteUnit {
-    fn partial_cmp(&self, other: &T) -> Option<Ordering> {
-        self.0.partial_cmp(&(*other).into().0)
+    /// Shifts `self` left by `n` bits, saturating at
+    /// [`ByteUnit::max_value()`] if any set bit would be shifted out. A zero
+    /// value stays zero for any `n`.
+    ///
+    /// Unlike the `Shl` operator, whose right-hand side is converted into a
+    /// `ByteUnit` and then read as a bit count, `n` is plainly a number of
+    /// bits.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteUnit, ToByteUnit};
+    ///
+    /// assert_eq!(4.kibibytes().shl_bits(2), 16.kibibytes());
+    /// assert_eq!(ByteUnit::EiB.shl_bits(3), 8.exbibytes());
+    /// assert_eq!(ByteUnit::EiB.shl_bits(4), ByteUnit::max_value());
+    /// assert_eq!(1.bytes().shl_bits(64), ByteUnit::max_value());
+    /// assert_eq!(0.bytes().shl_bits(100), 0);
+    /// ```
+    #[inline(always)]
+    pub const fn shl_bits(self, n: u32) -> ByteUnit {
+        if self.0 == 0 {
+            self
+        } else if n > self.0.leading_zeros() {
+            ByteUnit::max_value()
+        } else {
+            ByteUnit(self.0 << n)
+        }
     }
-}
 
-macro_rules! impl_self_assign_op {
-    ($Trait:ident, $func:ident, $op:tt) => (
-        impl<T: Into<ByteUnit>> $Trait<T> for ByteUnit {
-            #[inline(always)]
-            fn $func(&mut self, rhs: T) {
-                *self = *self $op rhs.into();
-            }
+    /// Shifts `self` right by `n` bits. Shifting by 64 or more bits yields
+    /// `0`.
+    ///
+    /// Unlike the `Shr` operator, whose right-hand side is converted into a
+    /// `ByteUnit` and then read as a bit count, `n` is plainly a number of
+    /// bits.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteUnit, ToByteUnit};
+    ///
+    /// assert_eq!(16.kibibytes().shr_bits(2), 4.kibibytes());
+    /// assert_eq!(3.bytes().shr_bits(1), 1.bytes());
+    /// assert_eq!(ByteUnit::max_value().shr_bits(64), 0);
+    /// ```
+    #[inline(always)]
+    pub const fn shr_bits(self, n: u32) -> ByteUnit {
+        match self.0.checked_shr(n) {
+            Some(value) => ByteUnit(value),
+            None => ByteUnit(0),
         }
-    )
-}
+    }
 
-impl_self_assign_op!(AddAssign, add_assign, +);
-impl_self_assign_op!(SubAssign, sub_assign, -);
-impl_self_assign_op!(MulAssign, mul_assign, *);
-impl_self_assign_op!(DivAssign, div_assign, /);
-impl_self_assign_op!(RemAssign, rem_assign, %);
-impl_self_assign_op!(ShrAssign, shr_assign, >>);
-impl_self_assign_op!(ShlAssign, shl_assign, <<);
+    /// Returns the ratio of `self` to `other` as an `f64`: `self / other`.
+    ///
+    /// Unlike `Div`, which performs saturating integer division, the division
+    /// is performed in floating point. As with `f64` division, a zero `other`
+    /// yields [`f64::INFINITY`] unless `self` is also zero, in which case the
+    /// result is [`f64::NAN`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteUnit, ToByteUnit};
+    ///
+    /// assert_eq!(512.mebibytes().ratio(2.gibibytes()), 0.25);
+    /// assert_eq!(3.kilobytes().ratio(2.kilobytes()), 1.5);
+    /// assert_eq!(3.kilobytes() / 2.kilobytes(), 1);
+    ///
+    /// assert_eq!(1.bytes().ratio(0), f64::INFINITY);
+    /// assert!(0.bytes().ratio(0).is_nan());
+    /// ```
+    #[inline(always)]
+    pub fn ratio<T: Into<ByteUnit>>(self, other: T) -> f64 {
+        self.0 as f64 / other.into().0 as f64
+    }
 
-macro_rules! impl_arith_op_on_core_type {
-    ($T:ident, $Trait:ident, $func:ident, $op:tt) => (
-        impl $Trait<ByteUnit> for $T {
-            type Output = ByteUnit;
+    /// Returns `self` as a percentage of `whole`: `self / whole * 100`.
+    ///
+    /// The percentage is computed in floating point via
+    /// [`ByteUnit::ratio()`] and shares its behavior for a zero `whole`: the
+    /// result is [`f64::INFINITY`] unless `self` is also zero, in which case
+    /// it is [`f64::NAN`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteUnit, ToByteUnit};
+    ///
+    /// assert_eq!(512.mebibytes().percent_of(2.gibibytes()), 25.0);
+    /// assert_eq!(3.kilobytes().percent_of(2.kilobytes()), 150.0);
+    ///
+    /// assert_eq!(1.bytes().percent_of(0), f64::INFINITY);
+    /// assert!(0.bytes().percent_of(0).is_nan());
+    /// ```
+    #[inline(always)]
+    pub fn percent_of<T: Into<ByteUnit>>(self, whole: T) -> f64 {
+        self.ratio(whole) * 100.0
+    }
 
-            #[inline(always)]
-            fn $func(self, rhs: ByteUnit) -> Self::Output {
-                ByteUnit::from(self) $op rhs
-            }
+    /// Returns `self` expressed as a number of `unit`s: `self / unit`.
+    ///
+    /// This is [`ByteUnit::ratio()`] with a unit divisor and shares its
+    /// behavior for a zero `unit`: the result is [`f64::INFINITY`] unless
+    /// `self` is also zero, in which case it is [`f64::NAN`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteUnit, ToByteUnit};
+    ///
+    /// assert_eq!(512.mebibytes().to_unit(ByteUnit::GiB), 0.5);
+    /// assert_eq!(1.kilobytes().to_unit(ByteUnit::KiB), 0.9765625);
+    /// assert_eq!(3.gigabytes().to_unit(ByteUnit::MB), 3000.0);
+    /// assert_eq!(1.bytes().to_unit(0.bytes()), f64::INFINITY);
+    /// ```
+    #[inline(always)]
+    pub fn to_unit(self, unit: ByteUnit) -> f64 {
+        self.ratio(unit)
+    }
+
+    /// Rounds `self` down to a multiple of `unit`. A zero `unit` leaves `self`
+    /// unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteUnit, ToByteUnit};
+    ///
+    /// assert_eq!((2.gigabytes() + 999.megabytes()).floor_to(ByteUnit::GB), 2.gigabytes());
+    /// assert_eq!(1.gigabytes().floor_to(ByteUnit::GB), 1.gigabytes());
+    /// assert_eq!(5.bytes().floor_to(0.bytes()), 5.bytes());
+    /// ```
+    pub const fn floor_to(self, unit: ByteUnit) -> ByteUnit {
+        match unit.0 {
+            0 => self,
+            unit => ByteUnit(self.0 - self.0 % unit),
         }
-    )
-}
+    }
 
-macro_rules! impl_arith_ops_on_core {
-    ($T:ident) => (
-        impl_arith_op_on_core_type!($T, Add, add, +);
-        impl_arith_op_on_core_type!($T, Sub, sub, -);
-        impl_arith_op_on_core_type!($T, Mul, mul, *);
-        impl_arith_op_on_core_type!($T, Div, div, /);
-        impl_arith_op_on_core_type!($T, Rem, rem, %);
-        impl_arith_op_on_core_type!($T, Shl, shl, <<);
-        impl_arith_op_on_core_type!($T, Shr, shr, >>);
-
-        impl PartialEq<ByteUnit> for $T {
-            #[inline(always)]
-            fn eq(&self, other: &ByteUnit) -> bool {
-                ByteUnit::from(*self).eq(other)
-            }
+    /// Rounds `self` up to a multiple of `unit`, saturating at
+    /// [`ByteUnit::max_value()`]. A zero `unit` leaves `self` unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteUnit, ToByteUnit};
+    ///
+    /// assert_eq!((1.mebibytes() + 1).ceil_to(ByteUnit::MiB), 2.mebibytes());
+    /// assert_eq!(1.mebibytes().ceil_to(ByteUnit::MiB), 1.mebibytes());
+    /// assert_eq!(ByteUnit::max_value().ceil_to(ByteUnit::MiB), ByteUnit::max_value());
+    /// assert_eq!(5.bytes().ceil_to(0.bytes()), 5.bytes());
+    /// ```
+    pub const fn ceil_to(self, unit: ByteUnit) -> ByteUnit {
+        let floor = self.floor_to(unit);
+        match floor.0 == self.0 {
+            true => self,
+            false => ByteUnit(floor.0.saturating_add(unit.0)),
         }
+    }
 
-        impl PartialOrd<ByteUnit> for $T {
-            #[inline(always)]
-            fn partial_cmp(&self, other: &ByteUnit) -> Option<Ordering> {
-                ByteUnit::from(*self).partial_cmp(other)
-            }
+    /// Rounds `self` to the nearest multiple of `unit`, rounding ties up and
+    /// saturating at [`ByteUnit::max_value()`]. A zero `unit` leaves `self`
+    /// unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteUnit, ToByteUnit};
+    ///
+    /// assert_eq!((2.gigabytes() + 499.megabytes()).round_to(ByteUnit::GB), 2.gigabytes());
+    /// assert_eq!((2.gigabytes() + 500.megabytes()).round_to(ByteUnit::GB), 3.gigabytes());
+    /// assert_eq!(ByteUnit::max_value().round_to(ByteUnit::GiB), ByteUnit::max_value());
+    /// assert_eq!(5.bytes().round_to(0.bytes()), 5.bytes());
+    /// ```
+    pub const fn round_to(self, unit: ByteUnit) -> ByteUnit {
+        let floor = self.floor_to(unit);
+        match self.0 - floor.0 >= unit.0 - (self.0 - floor.0) {
+            true => self.ceil_to(unit),
+            false => floor,
         }
-    )
-}
+    }
 
-impl_arith_ops_on_core!(usize);
-impl_arith_ops_on_core!(u8);
-impl_arith_ops_on_core!(u16);
-impl_arith_ops_on_core!(u32);
-impl_arith_ops_on_core!(u64);
-impl_arith_ops_on_core!(u128);
+    /// Rounds `self` up to the next boundary of `align`, saturating at
+    /// [`ByteUnit::max_value()`]. `align` need not be a power of two. A zero
+    /// `align` leaves `self` unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteUnit, ToByteUnit};
+    ///
+    /// assert_eq!(5.kibibytes().align_up(4.kibibytes()), 8.kibibytes());
+    /// assert_eq!(8.kibibytes().align_up(4.kibibytes()), 8.kibibytes());
+    /// assert_eq!(10.bytes().align_up(3.bytes()), 12.bytes());
+    /// assert_eq!(ByteUnit::max_value().align_up(4.kibibytes()), ByteUnit::max_value());
+    /// assert_eq!(5.bytes().align_up(0.bytes()), 5.bytes());
+    /// ```
+    pub const fn align_up(self, align: ByteUnit) -> ByteUnit {
+        self.ceil_to(align)
+    }
 
-impl_arith_ops_on_core!(isize);
-impl_arith_ops_on_core!(i8);
-impl_arith_ops_on_core!(i16);
-impl_arith_ops_on_core!(i32);
-impl_arith_ops_on_core!(i64);
-impl_arith_ops_on_core!(i128);
+    /// Rounds `self` down to the previous boundary of `align`. `align` need
+    /// not be a power of two. A zero `align` leaves `self` unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteUnit, ToByteUnit};
+    ///
+    /// assert_eq!(5.kibibytes().align_down(4.kibibytes()), 4.kibibytes());
+    /// assert_eq!(8.kibibytes().align_down(4.kibibytes()), 8.kibibytes());
+    /// assert_eq!(10.bytes().align_down(3.bytes()), 9.bytes());
+    /// assert_eq!(5.bytes().align_down(0.bytes()), 5.bytes());
+    /// ```
+    pub const fn align_down(self, align: ByteUnit) -> ByteUnit {
+        self.floor_to(align)
+    }
+}
 
 #[cfg(test)]
 mod tests {
-    use crate::{ByteUnit, ToByteUnit};
+    use crate::{ByteUnit, ToByteUnit, BitUnit, ToBitUnit};
 
     #[test]
     fn test_saturation() {
@@ -188,6 +899,54 @@ mod tests {
         assert!((500 + 700) > 2.bytes());
     }
 
+    #[test]
+    fn test_shift_operators() {
+        // The right-hand side is a `ByteUnit` whose value is the bit count.
+        assert_eq!(4.kibibytes() << 2, 16.kibibytes());
+        assert_eq!(4.kibibytes() << 2.bytes(), 16.kibibytes());
+        assert_eq!(4.kibibytes() << 4.kibibytes(), ByteUnit::max_value());
+        assert_eq!(16.kibibytes() >> 2, 4.kibibytes());
+        assert_eq!(16.kibibytes() >> 4.kibibytes(), 0);
+
+        // Saturation boundaries.
+        assert_eq!(1.bytes() << 63, 1u64 << 63);
+        assert_eq!(1.bytes() << 64, ByteUnit::max_value());
+        assert_eq!(ByteUnit::max_value() << 0, ByteUnit::max_value());
+        assert_eq!(ByteUnit::max_value() << 1, ByteUnit::max_value());
+        assert_eq!(0.bytes() << 63, 0);
+        assert_eq!(0.bytes() << 64, 0);
+        assert_eq!(0.bytes() << ByteUnit::max_value(), 0);
+        assert_eq!(ByteUnit::max_value() >> 63, 1);
+        assert_eq!(ByteUnit::max_value() >> 64, 0);
+        assert_eq!(ByteUnit::max_value() >> ByteUnit::max_value(), 0);
+        assert_eq!(BitUnit::max_value() >> 64, 0);
+        assert_eq!(BitUnit::from(0) << 64, 0);
+        assert_eq!(BitUnit::from(1) << 64, BitUnit::max_value());
+
+        let mut b = 1.kibibytes();
+        b <<= 1;
+        b >>= 3;
+        assert_eq!(b, 256);
+    }
+
+    #[test]
+    fn test_shift_bits() {
+        const SHL: ByteUnit = ByteUnit::Kibibyte(4).shl_bits(2);
+        const SHR: ByteUnit = ByteUnit::Kibibyte(4).shr_bits(2);
+        assert_eq!(SHL, 16.kibibytes());
+        assert_eq!(SHR, 1.kibibytes());
+
+        assert_eq!(1.bytes().shl_bits(63), 1u64 << 63);
+        assert_eq!(1.bytes().shl_bits(64), ByteUnit::max_value());
+        assert_eq!(1.bytes().shl_bits(u32::MAX), ByteUnit::max_value());
+        assert_eq!(0.bytes().shl_bits(u32::MAX), 0);
+        assert_eq!(ByteUnit::max_value().shl_bits(0), ByteUnit::max_value());
+        assert_eq!(ByteUnit::max_value().shr_bits(63), 1);
+        assert_eq!(ByteUnit::max_value().shr_bits(64), 0);
+        assert_eq!(ByteUnit::max_value().shr_bits(u32::MAX), 0);
+        assert_eq!(5.bytes().shr_bits(0), 5);
+    }
+
     #[test]
     fn test_add_assign_op() {
         let mut b = 0.bytes();
@@ -198,4 +957,307 @@ mod tests {
         b *= 100.kibibytes();
         assert_eq!(b, 1024.kilobytes());
     }
+
+    #[test]
+    fn test_bit_unit_operations() {
+        assert_eq!(BitUnit::bit * -1, 0);
+        assert_eq!(BitUnit::bit / 0, BitUnit::max_value());
+        assert_eq!(BitUnit::Eibit << 4, BitUnit::max_value());
+        assert_eq!(1000 - 300.bits(), 700);
+        assert_eq!(1.gigabits() / 8, 125.megabits());
+        assert!(1.kibibits() > 1.kilobits());
+
+        let mut b = 10.bits();
+        b *= 100.kibibits();
+        assert_eq!(b, 1024.kilobits());
+        assert_eq!(b.to_bytes(), 128.kilobytes());
+        assert_eq!(b.to_bytes().to_bits(), b);
+        assert_eq!(ByteUnit::max_value().to_bits(), BitUnit::max_value());
+        assert_eq!((ByteUnit::max_value() / 8).to_bits(), BitUnit::max_value() - 7);
+    }
+
+    #[test]
+    fn test_sum() {
+        let sizes = [1.kilobytes(), 24.bytes(), 1.kibibytes()];
+        assert_eq!(sizes.iter().sum::<ByteUnit>(), 2.kibibytes());
+        assert_eq!(sizes.iter().copied().sum::<ByteUnit>(), 2.kibibytes());
+        assert_eq!([].iter().sum::<ByteUnit>(), 0);
+
+        let sizes = [8.exbibytes(), 8.exbibytes(), 1.bytes(), 8.exbibytes()];
+        assert_eq!(sizes.iter().sum::<ByteUnit>(), ByteUnit::max_value());
+        assert_eq!(sizes.iter().copied().sum::<ByteUnit>(), ByteUnit::max_value());
+        assert_eq!((0..).take(4).map(|_| 1.exbibits()).sum::<BitUnit>(), 4.exbibits());
+    }
+
+    #[test]
+    fn test_checked() {
+        assert_eq!(ByteUnit::max_value().checked_add(0), Some(ByteUnit::max_value()));
+        assert_eq!(ByteUnit::max_value().checked_add(1.bytes()), None);
+        assert_eq!(0.bytes().checked_sub(0), Some(0.bytes()));
+        assert_eq!(0.bytes().checked_sub(1), None);
+        assert_eq!(8.exbibytes().checked_mul(2), None);
+        assert_eq!(8.exbibytes().checked_mul(1), Some(8.exbibytes()));
+        assert_eq!(10.bytes().checked_div(3), Some(3.bytes()));
+        assert_eq!(10.bytes().checked_div(0.bytes()), None);
+
+        // Negative integers convert to `0` as they do everywhere else.
+        assert_eq!(10.bytes().checked_sub(-5), Some(10.bytes()));
+        assert_eq!(10.bytes().checked_div(-5), None);
+    }
+
+    #[test]
+    fn test_overflowing_and_wrapping() {
+        let cases = [(0u64, 0u64), (0, 1), (u64::MAX, 1), (u64::MAX, u64::MAX), (1 << 32, 1 << 32)];
+        for &(a, b) in &cases {
+            let (x, y) = (ByteUnit::from(a), ByteUnit::from(b));
+            let (add, sub) = (a.overflowing_add(b), a.overflowing_sub(b));
+            let mul = a.overflowing_mul(b);
+            assert_eq!(x.overflowing_add(y), (add.0.bytes(), add.1));
+            assert_eq!(x.overflowing_sub(y), (sub.0.bytes(), sub.1));
+            assert_eq!(x.overflowing_mul(y), (mul.0.bytes(), mul.1));
+            assert_eq!(x.wrapping_add(y), a.wrapping_add(b));
+            assert_eq!(x.wrapping_sub(y), a.wrapping_sub(b));
+            assert_eq!(x.wrapping_mul(y), a.wrapping_mul(b));
+        }
+    }
+
+    #[test]
+    fn test_abs_diff() {
+        assert_eq!(5.bytes().abs_diff(3), 2);
+        assert_eq!(3.bytes().abs_diff(5), 2);
+        assert_eq!(5.bytes().abs_diff(5), 0);
+        assert_eq!(0.bytes().abs_diff(ByteUnit::max_value()), ByteUnit::max_value());
+        assert_eq!(ByteUnit::max_value().abs_diff(0), ByteUnit::max_value());
+        assert_eq!(ByteUnit::max_value().abs_diff(ByteUnit::max_value()), 0);
+        assert_eq!(1.mebibytes().abs_diff(1.megabytes()), 48_576);
+    }
+
+    #[test]
+    fn test_ratio() {
+        assert_eq!(1.kibibytes().ratio(1.kibibytes()), 1.0);
+        assert_eq!(1.kibibytes().ratio(4.kibibytes()), 0.25);
+        assert_eq!(0.bytes().ratio(4.kibibytes()), 0.0);
+        assert_eq!(ByteUnit::max_value().ratio(ByteUnit::max_value()), 1.0);
+        assert_eq!(5.bytes().ratio(0.bytes()), f64::INFINITY);
+        assert!(0.bytes().ratio(0.bytes()).is_nan());
+
+        assert_eq!(1.kibibytes().percent_of(1.kibibytes()), 100.0);
+        assert_eq!(1.kibibytes().percent_of(8.kibibytes()), 12.5);
+        assert_eq!(0.bytes().percent_of(8.kibibytes()), 0.0);
+        assert_eq!(5.bytes().percent_of(0.bytes()), f64::INFINITY);
+        assert!(0.bytes().percent_of(0.bytes()).is_nan());
+    }
+
+    #[test]
+    fn test_round_to() {
+        for unit in [ByteUnit::kB, ByteUnit::KiB, 3.bytes(), 7.mebibytes()] {
+            assert_eq!(0.bytes().floor_to(unit), 0);
+            assert_eq!(0.bytes().ceil_to(unit), 0);
+            assert_eq!(0.bytes().round_to(unit), 0);
+            assert_eq!((unit * 5).floor_to(unit), unit * 5);
+            assert_eq!((unit * 5).ceil_to(unit), unit * 5);
+            assert_eq!((unit * 5).round_to(unit), unit * 5);
+            let tie = if unit % 2 == 0 { unit * 6 } else { unit * 5 };
+            assert_eq!((unit * 5 + unit / 2).round_to(unit), tie);
+            assert_eq!((unit * 5 + 1).floor_to(unit), unit * 5);
+            assert_eq!((unit * 5 + 1).ceil_to(unit), unit * 6);
+            assert_eq!((unit * 5 + 1).round_to(unit), unit * 5);
+        }
+
+        assert_eq!(17.bytes().floor_to(ByteUnit::B), 17);
+        assert_eq!(17.bytes().ceil_to(ByteUnit::B), 17);
+        assert_eq!(17.bytes().round_to(ByteUnit::B), 17);
+
+        assert_eq!(7.bytes().round_to(3.bytes()), 6);
+        assert_eq!(8.bytes().round_to(3.bytes()), 9);
+        assert_eq!(5.bytes().round_to(2.bytes()), 6);
+        assert_eq!(ByteUnit::max_value().floor_to(2.bytes()), ByteUnit::max_value() - 1);
+        assert_eq!(ByteUnit::max_value().ceil_to(2.bytes()), ByteUnit::max_value());
+        assert_eq!(ByteUnit::max_value().round_to(2.bytes()), ByteUnit::max_value());
+        assert_eq!(ByteUnit::max_value().floor_to(ByteUnit::max_value()), ByteUnit::max_value());
+        assert_eq!(1.bytes().round_to(ByteUnit::max_value()), 0);
+    }
+
+    #[test]
+    fn test_div_count() {
+        assert_eq!(1.gibibytes().div_count(1.mebibytes()), 1024);
+        assert_eq!(1.gibibytes().div_count(1.mebibytes()), 1.gibibytes() / 1.mebibytes());
+        assert_eq!(ByteUnit::max_value().div_count(1), u64::MAX);
+        assert_eq!(ByteUnit::max_value().div_count(2.bytes()), u64::MAX / 2);
+        assert_eq!(7.bytes().div_count(8u8), 0);
+        assert_eq!(ByteUnit::max_value().div_count(0), u64::MAX);
+        assert_eq!(0.bytes().div_count(ByteUnit::ZERO), (0.bytes() / 0).as_u64());
+    }
+
+    #[test]
+    fn test_div_ceil_count() {
+        assert_eq!((4.kibibytes() + 1).div_ceil_count(4.kibibytes()), 2);
+        assert_eq!((4.kibibytes() - 1).div_ceil_count(4.kibibytes()), 1);
+        assert_eq!(ByteUnit::max_value().div_ceil_count(1), u64::MAX);
+        assert_eq!(ByteUnit::max_value().div_ceil_count(2), u64::MAX / 2 + 1);
+        assert_eq!(ByteUnit::max_value().div_ceil_count(ByteUnit::max_value()), 1);
+        assert_eq!(0.bytes().div_ceil_count(0), u64::MAX);
+    }
+
+    #[test]
+    fn test_eq_approx() {
+        let max = ByteUnit::max_value();
+        assert!(max.eq_approx(0, max));
+        assert!(!max.eq_approx(0, max - 1));
+        assert!(0.bytes().eq_approx(max, max));
+        assert!(max.eq_approx(max, 0.bytes()));
+        assert!(5.bytes().eq_approx(3, 2.bytes()));
+        assert!(3.bytes().eq_approx(5, 2.bytes()));
+        assert!(!3.bytes().eq_approx(6, 2.bytes()));
+        assert!(!6.bytes().eq_approx(3, 2.bytes()));
+    }
+
+    #[test]
+    fn test_overshoot() {
+        let max = ByteUnit::max_value();
+        for (a, b) in [(0, 0), (0, 1), (1, 0), (u64::MAX, 0), (0, u64::MAX), (7, 3)] {
+            let (a, b) = (a.bytes(), b.bytes());
+            assert_eq!(a.overshoot(b), b - a);
+            assert_eq!(a.overshoot(b) == 0, a.checked_sub(b).is_some());
+            assert!(a.overshoot(b) == 0 || a - b == 0);
+            assert_eq!(a.overshoot(b) + (a - b), a.abs_diff(b));
+        }
+
+        assert_eq!(0.bytes().overshoot(max), max);
+        assert_eq!(max.overshoot(0), 0);
+    }
+
+    #[test]
+    fn test_saturating_add_signed() {
+        const ADJUSTED: ByteUnit = ByteUnit::Kibibyte(1).saturating_add_signed(-24);
+        assert_eq!(ADJUSTED, 1000);
+
+        let max = ByteUnit::max_value();
+        assert_eq!(0.bytes().saturating_add_signed(0), 0);
+        assert_eq!(0.bytes().saturating_add_signed(-1), 0);
+        assert_eq!(0.bytes().saturating_add_signed(i64::MIN), 0);
+        assert_eq!(0.bytes().saturating_add_signed(i64::MAX), i64::MAX as u64);
+        assert_eq!(max.saturating_add_signed(i64::MAX), max);
+        assert_eq!(max.saturating_add_signed(i64::MIN), u64::MAX - (1 << 63));
+        assert_eq!((max - 10).saturating_add_signed(10), max);
+        assert_eq!((max - 10).saturating_add_signed(11), max);
+        assert_eq!(10.bytes().saturating_add_signed(-10), 0);
+        assert_eq!(10.bytes().saturating_add_signed(-11), 0);
+    }
+
+    #[test]
+    fn test_gcd_lcm() {
+        let max = ByteUnit::max_value();
+        assert_eq!(0.bytes().gcd(0), 0);
+        assert_eq!(0.bytes().lcm(0), 0);
+        assert_eq!(7.bytes().gcd(0), 7);
+        assert_eq!(7.bytes().lcm(0), 0);
+        assert_eq!(7.bytes().gcd(13), 1);
+        assert_eq!(7.bytes().lcm(13), 91);
+        assert_eq!(512.bytes().gcd(4096), 512);
+        assert_eq!(4096.bytes().lcm(512), 4096);
+        assert_eq!(max.gcd(max), max);
+        assert_eq!(max.lcm(max), max);
+        assert_eq!(max.lcm(2), max);
+        assert_eq!(ByteUnit::EiB.lcm(3), 3.exbibytes());
+        assert_eq!(ByteUnit::EiB.lcm(ByteUnit::EB), max);
+
+        let (a, b) = (ByteUnit::GB, ByteUnit::GiB);
+        assert_eq!(a.gcd(b) * a.lcm(b), a * b);
+    }
+
+    #[test]
+    fn test_growth() {
+        const DOUBLED: ByteUnit = ByteUnit::Kibibyte(2).double();
+        const GROWN: ByteUnit = ByteUnit::Kibibyte(2).grow_by_factor(5);
+        assert_eq!(DOUBLED, 4.kibibytes());
+        assert_eq!(GROWN, 10.kibibytes());
+
+        let mut size = 1.bytes();
+        for i in 1..=63 {
+            size = size.double();
+            assert_eq!(size, 1u64 << i);
+        }
+
+        assert_eq!(size.double(), ByteUnit::max_value());
+        assert_eq!(size.double().double(), ByteUnit::max_value());
+        assert_eq!((ByteUnit::max_value() / 2).double(), ByteUnit::max_value() - 1);
+        assert_eq!(ByteUnit::max_value().grow_by_factor(u32::MAX), ByteUnit::max_value());
+        assert_eq!(3.bytes().grow_by_factor(u32::MAX), 3 * u32::MAX as u64);
+    }
+
+    #[test]
+    fn test_mul_ratio() {
+        const THREE_QUARTERS: ByteUnit = ByteUnit::Mebibyte(4).mul_ratio(3, 4);
+        assert_eq!(THREE_QUARTERS, 3.mebibytes());
+
+        let max = ByteUnit::max_value();
+        assert_eq!(max.mul_ratio(u64::MAX, u64::MAX), max);
+        assert_eq!(max.mul_ratio(u64::MAX - 1, u64::MAX), max - 1);
+        assert_eq!(max.mul_ratio(1, u64::MAX), 1);
+        assert_eq!(max.mul_ratio(0, 1), 0);
+        assert_eq!(max.mul_ratio(2, 1), max);
+        assert_eq!(0.bytes().mul_ratio(0, 0), max);
+        assert_eq!(7.bytes().mul_ratio(1, 2), 3);
+        assert_eq!(1.gigabytes().mul_ratio(1024, 1000), 1_024_000_000);
+    }
+
+    #[test]
+    fn test_midpoint() {
+        let max = ByteUnit::max_value();
+        assert_ne!((max + max) / 2, max);
+        assert_eq!(max.midpoint(max), max);
+        assert_eq!(max.midpoint(0), u64::MAX / 2);
+        assert_eq!(0.bytes().midpoint(max), u64::MAX / 2);
+        assert_eq!((max - 1).midpoint(max - 3), max - 2);
+        assert_eq!(1.bytes().midpoint(2), 1);
+        assert_eq!(0.bytes().midpoint(0), 0);
+        assert_eq!(1.gibibytes().midpoint(2.gibibytes()), 1.gibibytes() + 512.mebibytes());
+    }
+
+    #[test]
+    fn test_align() {
+        const PAGE: ByteUnit = ByteUnit::Kibibyte(4);
+        const UP: ByteUnit = ByteUnit::Byte(1).align_up(PAGE);
+        const DOWN: ByteUnit = ByteUnit::Kibibyte(7).align_down(PAGE);
+        assert_eq!(UP, PAGE);
+        assert_eq!(DOWN, PAGE);
+        assert_eq!(0.bytes().align_up(PAGE), 0);
+        assert_eq!((PAGE - 1).align_down(PAGE), 0);
+        assert_eq!(1000.bytes().align_up(512.bytes()), 1024);
+        assert_eq!(1000.bytes().align_down(300.bytes()), 900);
+        assert_eq!((ByteUnit::max_value() - 1).align_up(PAGE), ByteUnit::max_value());
+        assert_eq!(ByteUnit::max_value().align_down(PAGE), ByteUnit::max_value() - 4095);
+        assert_eq!(7.bytes().align_up(0.bytes()), 7);
+        assert_eq!(7.bytes().align_down(0.bytes()), 7);
+    }
+
+    #[test]
+    fn test_total() {
+        let max = ByteUnit::max_value();
+        assert_eq!(ByteUnit::total([-5i32, 10, -1]), 10);
+        assert_eq!(ByteUnit::total([u128::MAX, 0]), max);
+        assert_eq!(ByteUnit::total((0..=255u8).map(|_| u64::MAX / 128)), max);
+        assert_eq!(ByteUnit::total(core::iter::repeat(1.kibibytes()).take(1024)), 1.mebibytes());
+        assert_eq!(ByteUnit::total(core::iter::empty::<u8>()), 0);
+        let sizes = [max - 1, 1.bytes(), 1.bytes()];
+        assert_eq!(ByteUnit::total(sizes), sizes.iter().sum::<ByteUnit>());
+    }
+
+    #[test]
+    fn test_saturating_methods() {
+        let values = [0, 1, 1000, 1024, u64::MAX / 2, u64::MAX - 1, u64::MAX];
+        for &a in &values {
+            for &b in &values {
+                let (a, b) = (a.bytes(), b.bytes());
+                assert_eq!(a.saturating_add(b), a + b);
+                assert_eq!(a.saturating_sub(b), a - b);
+                assert_eq!(a.saturating_mul(b), a * b);
+                assert_eq!(a.saturating_div(b), a / b);
+            }
+        }
+
+        assert_eq!(1.bytes().saturating_sub(-1i8), 1);
+        assert_eq!(ByteUnit::max_value().saturating_mul(2), ByteUnit::max_value());
+    }
 }
@@ -0,0 +1,264 @@
+use crate::ByteUnit;
+use crate::byte_unit::{IntRepr, Rounding, float_repr, precision, round_frac, promote};
+
+/// A unit of bits with saturating `const` constructors and arithmetic.
+///
+/// # Overview
+///
+/// A `BitUnit` is the bit-denominated sibling of [`ByteUnit`]: it represents
+/// a unit, a count, a number, of _bits_. As with `ByteUnit`, all operations on
+/// a `BitUnit` -- constructors, arithmetic, conversions -- saturate.
+///
+/// [`ToBitUnit`] provides human-friendly methods on all integer types for
+/// converting into a `BitUnit`: [`100.megabits()`](ToBitUnit::megabits).
+///
+/// A `BitUnit` converts into a `ByteUnit` with [`BitUnit::to_bytes()`] and
+/// back with [`ByteUnit::to_bits()`].
+///
+/// # Example
+///
+/// ```rust
+/// use ubyte::{BitUnit, ToBitUnit, ToByteUnit};
+///
+/// // Construct with unit-valued associated constants, `const` constructors, or
+/// // human-friendly methods from the `ToBitUnit` integer extension trait.
+/// const GIGABIT: BitUnit = BitUnit::Megabit(1000);
+/// let gigabit = 1000 * BitUnit::Mbit;
+/// let gigabit = 1000.megabits();
+/// assert_eq!(gigabit, 1.gigabits());
+///
+/// // Conversions to and from `ByteUnit`.
+/// assert_eq!(1.gigabits().to_bytes(), 125.megabytes());
+/// assert_eq!(1.kibibytes().to_bits(), 8.kibibits());
+/// assert_eq!(12.bits().to_bytes(), 1.bytes());
+///
+/// // All arithmetic operations and conversions saturate.
+/// assert_eq!(BitUnit::Exbibit(32), BitUnit::max_value());
+/// assert_eq!(1.bits() - 2.bits(), 0);
+/// assert_eq!(4.exbibytes().to_bits(), BitUnit::max_value());
+///
+/// assert_eq!(10.megabits().to_string(), "10 Mbit");
+/// ```
+#[repr(transparent)]
+#[derive(Debug, Default, Copy, Clone, Eq, Hash, Ord)]
+pub struct BitUnit(pub(crate) u64);
+
+impl BitUnit {
+    constructor_fns! { BitUnit, "bits" =>
+        bit, Bit = 1,
+        kbit, Kilobit = 1_000,
+        Kibit, Kibibit = 1 << 10,
+        Mbit, Megabit = 1_000_000,
+        Mibit, Mebibit = 1 << 20,
+        Gbit, Gigabit = 1_000_000_000,
+        Gibit, Gibibit = 1 << 30,
+        Tbit, Terabit = 1_000_000_000_000,
+        Tibit, Tebibit = 1 << 40,
+        Pbit, Petabit = 1_000_000_000_000_000,
+        Pibit, Pebibit = 1 << 50,
+        Ebit, Exabit = 1_000_000_000_000_000_000,
+        Eibit, Exbibit = 1 << 60,
+    }
+
+    /// The maximum value of bits representable by `BitUnit`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ubyte::BitUnit;
+    /// assert_eq!(BitUnit::max_value(), u64::MAX);
+    /// ```
+    pub const fn max_value() -> BitUnit {
+        BitUnit(u64::MAX)
+    }
+
+    /// Returns the value of bits represented by `self` as a `u64`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ubyte::BitUnit;
+    /// assert_eq!(BitUnit::Megabit(42).as_u64(), 42 * 1_000_000);
+    /// ```
+    pub const fn as_u64(self) -> u64 {
+        self.0
+    }
+
+    /// Returns the value of bits represented by `self` as a `u128`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ubyte::BitUnit;
+    /// assert_eq!(BitUnit::Megabit(42).as_u128(), 42 * 1_000_000);
+    /// ```
+    pub const fn as_u128(self) -> u128 {
+        self.0 as u128
+    }
+
+    /// Converts `self` into the `ByteUnit` it occupies, rounding toward zero
+    /// when `self` isn't a whole number of bytes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ToBitUnit, ToByteUnit};
+    ///
+    /// assert_eq!(100.megabits().to_bytes(), 12_500.kilobytes());
+    /// assert_eq!(8.kibibits().to_bytes(), 1.kibibytes());
+    /// assert_eq!(15.bits().to_bytes(), 1.bytes());
+    /// assert_eq!(7.bits().to_bytes(), 0);
+    /// ```
+    pub const fn to_bytes(self) -> ByteUnit {
+        ByteUnit::from_bits(self.0)
+    }
+
+    /// Returns the components of the minimal unit representation of `self`.
+    ///
+    /// This is exactly [`ByteUnit::repr()`] for bits: the four components
+    /// returned are `(whole, frac, suffix, unit)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{BitUnit, ToBitUnit};
+    ///
+    /// let (whole, frac, suffix, unit) = (2.mebibits() + 512.kibibits()).repr();
+    /// assert_eq!(whole, 2);
+    /// assert_eq!(frac, 0.5);
+    /// assert_eq!(suffix, "Mibit");
+    /// assert_eq!(unit, BitUnit::Mibit);
+    /// ```
+    pub fn repr(self) -> (u64, f64, &'static str, BitUnit) {
+        float_repr(self.int_repr())
+    }
+
+    /// Returns [`BitUnit::repr()`] with the remainder, in bits, in place of
+    /// the fraction.
+    pub(crate) fn int_repr(self) -> IntRepr<BitUnit> {
+        rem_and_suffix! { BitUnit, self.as_u64() =>
+            (Eibit, Ebit), (Tibit, Tbit), (Gibit, Gbit), (Mibit, Mbit), (Kibit, kbit) bit
+        }
+    }
+}
+
+impl ByteUnit {
+    /// Converts `self` into the `BitUnit` of the same size. The conversion is
+    /// lossless unless `self` exceeds [`BitUnit::max_value()`], `2EiB`, in
+    /// which case it saturates.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{BitUnit, ToBitUnit, ToByteUnit};
+    ///
+    /// assert_eq!(125.megabytes().to_bits(), 1.gigabits());
+    /// assert_eq!(3.bytes().to_bits(), 24.bits());
+    /// assert_eq!(2.exbibytes().to_bits(), BitUnit::max_value());
+    /// ```
+    pub const fn to_bits(self) -> BitUnit {
+        BitUnit(self.as_bits())
+    }
+}
+
+impl From<BitUnit> for u64 {
+    #[inline(always)]
+    fn from(v: BitUnit) -> Self {
+        v.as_u64()
+    }
+}
+
+impl From<BitUnit> for u128 {
+    #[inline(always)]
+    fn from(v: BitUnit) -> Self {
+        v.as_u128()
+    }
+}
+
+impl_from_ints!(BitUnit);
+
+/// Extension trait for conversion from integer types to [`BitUnit`].
+///
+/// The `ToBitUnit` trait is the bit-denominated counterpart of
+/// [`ToByteUnit`](crate::ToByteUnit). It is implemented for all integer types.
+/// As with all other `BitUnit` operations, conversions saturate.
+///
+/// # Example
+///
+/// ```rust
+/// use ubyte::ToBitUnit;
+///
+/// assert_eq!(512.kilobits(), 512000.bits());
+/// assert_eq!(512.kibibits(), 524288.bits());
+/// assert_eq!(1000.megabits(), 1.gigabits());
+/// assert_eq!((1 << 10).exbibits(), (1 << 20).exbibits());
+/// ```
+pub trait ToBitUnit: Into<BitUnit> {
+    /// Converts `self` to a `BitUnit` representing `self` bits.
+    #[inline(always)]
+    fn bits(self) -> BitUnit {
+        self.into()
+    }
+
+    helper_fn!(BitUnit, bits => kilobits = kbit);
+    helper_fn!(BitUnit, bits => kibibits = Kibit);
+    helper_fn!(BitUnit, bits => megabits = Mbit);
+    helper_fn!(BitUnit, bits => mebibits = Mibit);
+    helper_fn!(BitUnit, bits => gigabits = Gbit);
+    helper_fn!(BitUnit, bits => gibibits = Gibit);
+    helper_fn!(BitUnit, bits => terabits = Tbit);
+    helper_fn!(BitUnit, bits => tebibits = Tibit);
+    helper_fn!(BitUnit, bits => petabits = Pbit);
+    helper_fn!(BitUnit, bits => pebibits = Pibit);
+    helper_fn!(BitUnit, bits => exabits = Ebit);
+    helper_fn!(BitUnit, bits => exbibits = Eibit);
+}
+
+impl<T: Into<BitUnit> + Copy> ToBitUnit for T {}
+
+/// Display `self` as best as possible, as with `ByteUnit`, but with a space
+/// between the value and the suffix.
+///
+/// # Example
+///
+/// ```rust
+/// use ubyte::ToBitUnit;
+///
+/// assert_eq!(10.megabits().to_string(), "10 Mbit");
+/// assert_eq!(3.kibibits().to_string(), "3 Kibit");
+/// assert_eq!(7.bits().to_string(), "7 bit");
+/// assert_eq!((2.mebibits() + 512.kibibits()).to_string(), "2.50 Mibit");
+/// assert_eq!(format!("{:.1}", 7.gibibits() + 920.mebibits()), "7.9 Gibit");
+/// assert_eq!(format!("{:.0}", 7.gibibits() + 920.mebibits()), "8 Gibit");
+/// ```
+impl core::fmt::Display for BitUnit {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let (whole, rem, suffix, unit) = self.int_repr();
+        let width = f.width().unwrap_or(0);
+        let p = precision(f);
+        match round_frac(rem, unit.as_u64(), p, Rounding::Standard) {
+            Some(frac) if rem != 0 && p > 0 => {
+                write!(f, "{:0width$}.{:0p$} {}", whole, frac, suffix, p = p, width = width)
+            }
+            Some(_) => write!(f, "{:0width$} {}", whole, suffix, width = width),
+            None => match promote(whole, unit.as_u64()) {
+                Some(next) => BitUnit(next).fmt(f),
+                None => write!(f, "{:0width$} {}", whole as u128 + 1, suffix, width = width),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod bit_unit_tests {
+    use crate::{BitUnit, ToBitUnit};
+
+    #[test]
+    fn test_display_rounding_carry() {
+        extern crate std;
+        use std::format;
+
+        assert_eq!(format!("{}", 100.kibibits() - 1), "100 Kibit");
+        assert_eq!(format!("{}", BitUnit::max_value()), "16 Eibit");
+    }
+}
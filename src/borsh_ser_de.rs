@@ -0,0 +1,43 @@
+use borsh::io::{Read, Result, Write};
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::ByteUnit;
+
+/// Serializes a `ByteUnit` exactly as a `u64`: eight little-endian bytes.
+impl BorshSerialize for ByteUnit {
+    #[inline(always)]
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.as_u64().serialize(writer)
+    }
+}
+
+/// Deserializes a `ByteUnit` from a `u64`: eight little-endian bytes.
+impl BorshDeserialize for ByteUnit {
+    #[inline(always)]
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        u64::deserialize_reader(reader).map(ByteUnit)
+    }
+}
+
+#[cfg(test)]
+mod borsh_tests {
+    use crate::{ByteUnit, ToByteUnit};
+
+    #[test]
+    fn test_borsh() {
+        for value in [0.bytes(), 512.kibibytes(), 7.gigabytes(), ByteUnit::max_value()] {
+            let bytes = borsh::to_vec(&value).unwrap();
+            assert_eq!(bytes, value.as_u64().to_le_bytes());
+            assert_eq!(bytes, borsh::to_vec(&value.as_u64()).unwrap());
+            assert_eq!(borsh::from_slice::<ByteUnit>(&bytes).unwrap(), value);
+        }
+
+        let pair = (1.mebibytes(), 2u8);
+        let bytes = borsh::to_vec(&pair).unwrap();
+        assert_eq!(bytes.len(), 9);
+        assert_eq!(borsh::from_slice::<(ByteUnit, u8)>(&bytes).unwrap(), pair);
+
+        assert!(borsh::from_slice::<ByteUnit>(&[0; 7]).is_err());
+        assert!(borsh::from_slice::<ByteUnit>(&[0; 9]).is_err());
+    }
+}
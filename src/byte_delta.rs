@@ -0,0 +1,319 @@
+use core::ops::{Add, Sub, Neg};
+
+use crate::ByteUnit;
+use crate::byte_unit::{CharCount, write_padded};
+
+/// A signed difference between two [`ByteUnit`]s, such as `-200MB`.
+///
+/// # Overview
+///
+/// Subtracting `ByteUnit`s saturates at zero, so a `ByteUnit` cannot express
+/// that a size _shrank_. A `ByteDelta` can: it is the signed number of bytes
+/// between two `ByteUnit`s, constructed with [`ByteUnit::delta()`],
+/// [`ByteDelta::new()`], or from a `ByteUnit`, and applied to a `ByteUnit`
+/// with [`ByteUnit::apply()`].
+///
+/// The magnitude of a `ByteDelta` never exceeds [`ByteUnit::max_value()`], so
+/// any two `ByteUnit`s have an exact delta and negation never overflows.
+/// Arithmetic on `ByteDelta`s saturates at these bounds.
+///
+/// # Example
+///
+/// ```rust
+/// use ubyte::{ByteDelta, ToByteUnit};
+///
+/// let (before, after) = (1.gigabytes(), 800.megabytes());
+/// let delta = after.delta(before);
+/// assert!(delta.is_negative());
+/// assert_eq!(delta.magnitude(), 200.megabytes());
+/// assert_eq!(delta.to_string(), "-200MB");
+/// assert_eq!((-delta).to_string(), "200MB");
+/// assert_eq!(format!("{:+}", -delta), "+200MB");
+///
+/// assert_eq!(before.apply(delta), after);
+/// assert_eq!(after.apply(-delta), before);
+/// assert_eq!(100.megabytes().apply(delta), 0);
+/// ```
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ByteDelta(i128);
+
+impl ByteDelta {
+    /// A `ByteDelta` of zero bytes.
+    pub const ZERO: ByteDelta = ByteDelta(0);
+
+    /// Constructs a `ByteDelta` of `bytes` bytes, saturating at
+    /// ±[`ByteUnit::max_value()`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteDelta, ByteUnit, ToByteUnit};
+    ///
+    /// assert_eq!(ByteDelta::new(-1024).magnitude(), 1.kibibytes());
+    /// assert_eq!(ByteDelta::new(i128::MAX).magnitude(), ByteUnit::max_value());
+    /// assert_eq!(ByteDelta::new(i128::MIN), -ByteDelta::new(i128::MAX));
+    /// ```
+    pub const fn new(bytes: i128) -> ByteDelta {
+        const MAX: i128 = u64::MAX as i128;
+        match bytes {
+            bytes if bytes < -MAX => ByteDelta(-MAX),
+            bytes if bytes > MAX => ByteDelta(MAX),
+            bytes => ByteDelta(bytes),
+        }
+    }
+
+    /// Returns the signed number of bytes in `self`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::ToByteUnit;
+    ///
+    /// assert_eq!(1.kibibytes().delta(2.kibibytes()).as_i128(), -1024);
+    /// ```
+    pub const fn as_i128(self) -> i128 {
+        self.0
+    }
+
+    /// Returns the absolute number of bytes in `self` as a `ByteUnit`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::ToByteUnit;
+    ///
+    /// assert_eq!(1.kibibytes().delta(3.kibibytes()).magnitude(), 2.kibibytes());
+    /// assert_eq!(3.kibibytes().delta(1.kibibytes()).magnitude(), 2.kibibytes());
+    /// ```
+    pub const fn magnitude(self) -> ByteUnit {
+        ByteUnit(self.0.unsigned_abs() as u64)
+    }
+
+    /// Returns `true` if `self` is less than zero bytes.
+    pub const fn is_negative(self) -> bool {
+        self.0 < 0
+    }
+
+    /// Returns `true` if `self` is more than zero bytes.
+    pub const fn is_positive(self) -> bool {
+        self.0 > 0
+    }
+}
+
+impl ByteUnit {
+    /// Returns the signed [`ByteDelta`] from `other` to `self`: `self - other`
+    /// without saturating at zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteDelta, ToByteUnit};
+    ///
+    /// assert_eq!(3.kibibytes().delta(1.kibibytes()), ByteDelta::new(2048));
+    /// assert_eq!(1.kibibytes().delta(3.kibibytes()), ByteDelta::new(-2048));
+    /// assert_eq!(1.kibibytes().delta(1.kibibytes()), ByteDelta::ZERO);
+    /// ```
+    pub const fn delta(self, other: ByteUnit) -> ByteDelta {
+        ByteDelta(self.0 as i128 - other.0 as i128)
+    }
+
+    /// Returns `self` adjusted by `delta`, saturating at `0` and at
+    /// [`ByteUnit::max_value()`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteDelta, ByteUnit, ToByteUnit};
+    ///
+    /// assert_eq!(1.kibibytes().apply(ByteDelta::new(-24)), 1000);
+    /// assert_eq!(1.kibibytes().apply(ByteDelta::new(-2048)), 0);
+    /// assert_eq!(ByteUnit::max_value().apply(ByteDelta::new(1)), ByteUnit::max_value());
+    /// ```
+    pub const fn apply(self, delta: ByteDelta) -> ByteUnit {
+        match self.0 as i128 + delta.0 {
+            i128::MIN..=0 => ByteUnit(0),
+            value if value >= u64::MAX as i128 => ByteUnit::max_value(),
+            value => ByteUnit(value as u64),
+        }
+    }
+
+    /// Returns the suffix of the unit that best fits the difference `self -
+    /// other`, as chosen by [`ByteUnit::repr()`] for its magnitude, and the
+    /// signed difference in that unit.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(not(any(feature = "default-si", feature = "default-iec")))] {
+    /// use ubyte::ToByteUnit;
+    ///
+    /// assert_eq!(2.mebibytes().describe_diff(1.mebibytes()), ("MiB", 1.0));
+    /// assert_eq!(1.mebibytes().describe_diff(2.mebibytes()), ("MiB", -1.0));
+    /// assert_eq!(3.gigabytes().describe_diff(2500.megabytes()), ("MB", 500.0));
+    /// assert_eq!(1.kibibytes().describe_diff(2560), ("KiB", -1.5));
+    /// assert_eq!(7.bytes().describe_diff(7), ("B", 0.0));
+    /// # }
+    /// ```
+    pub fn describe_diff<T: Into<ByteUnit>>(self, other: T) -> (&'static str, f64) {
+        let delta = self.delta(other.into());
+        let (whole, frac, suffix, _) = delta.magnitude().repr();
+        let magnitude = whole as f64 + frac;
+        (suffix, if delta.is_negative() { -magnitude } else { magnitude })
+    }
+}
+
+impl From<ByteUnit> for ByteDelta {
+    #[inline(always)]
+    fn from(v: ByteUnit) -> Self {
+        ByteDelta(v.0 as i128)
+    }
+}
+
+impl Neg for ByteDelta {
+    type Output = ByteDelta;
+
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        ByteDelta(-self.0)
+    }
+}
+
+impl Add for ByteDelta {
+    type Output = ByteDelta;
+
+    #[inline(always)]
+    fn add(self, rhs: ByteDelta) -> Self::Output {
+        ByteDelta::new(self.0 + rhs.0)
+    }
+}
+
+impl Sub for ByteDelta {
+    type Output = ByteDelta;
+
+    #[inline(always)]
+    fn sub(self, rhs: ByteDelta) -> Self::Output {
+        ByteDelta::new(self.0 - rhs.0)
+    }
+}
+
+/// Display the magnitude of `self` as with `ByteUnit`, preceded by a `-` when
+/// `self` is negative. With the `+` flag, `{:+}`, a `+` precedes positive and
+/// zero deltas.
+///
+/// Unlike with `ByteUnit`, a width pads the signed value as a whole with the
+/// fill and alignment, right-aligned by default, as
+/// [`UnitDisplay::pad_whole(false)`](crate::UnitDisplay::pad_whole()) does.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(not(any(feature = "default-si", feature = "default-iec")))] {
+/// use ubyte::ByteDelta;
+///
+/// assert_eq!(ByteDelta::new(-1536).to_string(), "-1.50KiB");
+/// assert_eq!(ByteDelta::new(1536).to_string(), "1.50KiB");
+/// assert_eq!(format!("{:+.1}", ByteDelta::new(1536)), "+1.5KiB");
+/// assert_eq!(format!("{:+#}", ByteDelta::ZERO), "+0 B");
+///
+/// let shrunk = ByteDelta::new(-200_000_000);
+/// assert_eq!(format!("{:>8}", shrunk), "  -200MB");
+/// assert_eq!(format!("{:<8}|", shrunk), "-200MB  |");
+/// # }
+/// ```
+impl core::fmt::Display for ByteDelta {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let sign = match self.is_negative() {
+            true => "-",
+            false if f.sign_plus() => "+",
+            false => "",
+        };
+
+        let (magnitude, precision, alternate) = (self.magnitude(), f.precision(), f.alternate());
+        let write = |out: &mut dyn core::fmt::Write| {
+            out.write_str(sign)?;
+            match (precision, alternate) {
+                (Some(p), true) => write!(out, "{:#.*}", p, magnitude),
+                (Some(p), false) => write!(out, "{:.*}", p, magnitude),
+                (None, true) => write!(out, "{:#}", magnitude),
+                (None, false) => write!(out, "{}", magnitude),
+            }
+        };
+
+        let mut len = CharCount(0);
+        write(&mut len)?;
+        write_padded(f, len.0, |f| write(f))
+    }
+}
+
+#[cfg(test)]
+mod delta_tests {
+    use crate::{ByteUnit, ToByteUnit};
+
+    #[test]
+    #[cfg_attr(any(feature = "default-si", feature = "default-iec"), ignore = "expects Auto")]
+    fn test_byte_delta() {
+        use crate::ByteDelta;
+
+        const SHRINK: ByteDelta = ByteUnit::Kilobyte(1).delta(ByteUnit::Kibibyte(1));
+        assert_eq!(SHRINK.as_i128(), -24);
+        assert_eq!(SHRINK.magnitude(), 24);
+
+        let max = ByteUnit::max_value();
+        let (up, down) = (max.delta(ByteUnit::ZERO), ByteUnit::ZERO.delta(max));
+        assert_eq!(up.as_i128(), u64::MAX as i128);
+        assert_eq!(down.as_i128(), -(u64::MAX as i128));
+        assert_eq!(-up, down);
+        assert_eq!(-down, up);
+        assert_eq!(up + up, up);
+        assert_eq!(down + down, down);
+        assert_eq!(down - up, down);
+        assert_eq!(up + down, ByteDelta::ZERO);
+        assert_eq!(up.magnitude(), max);
+        assert_eq!(down.magnitude(), max);
+        assert_eq!(ByteDelta::from(max), up);
+        assert_eq!(-ByteDelta::ZERO, ByteDelta::ZERO);
+        assert!(!ByteDelta::ZERO.is_negative() && !ByteDelta::ZERO.is_positive());
+        assert!(down < ByteDelta::ZERO && ByteDelta::ZERO < up);
+
+        assert_eq!(ByteUnit::ZERO.apply(up), max);
+        assert_eq!(max.apply(down), 0);
+        assert_eq!(max.apply(up), max);
+        assert_eq!(ByteUnit::ZERO.apply(down), 0);
+        for (a, b) in [(0, 0), (1, 0), (0, 1), (u64::MAX, 1), (1 << 40, u64::MAX)] {
+            let (a, b) = (a.bytes(), b.bytes());
+            assert_eq!(b.apply(a.delta(b)), a);
+            assert_eq!(a.apply(b.delta(a)), b);
+        }
+
+        assert_eq!(max.describe_diff(0), ("EiB", 16.0));
+        assert_eq!(ByteUnit::ZERO.describe_diff(max), ("EiB", -16.0));
+        assert_eq!(ByteUnit::ZERO.describe_diff(0), ("B", 0.0));
+        assert_eq!(1.kilobytes().describe_diff(1.kibibytes()), ("B", -24.0));
+        assert_eq!(5.megabytes().describe_diff(2.megabytes()), ("MB", 3.0));
+    }
+
+    #[test]
+    #[cfg_attr(any(feature = "default-si", feature = "default-iec"), ignore = "expects Auto")]
+    fn test_byte_delta_display() {
+        extern crate std;
+        use std::{format, string::ToString};
+        use crate::ByteDelta;
+
+        let shrunk = 800.megabytes().delta(1.gigabytes());
+        assert_eq!(shrunk.to_string(), "-200MB");
+        assert_eq!(format!("{:#}", shrunk), "-200 MB");
+        assert_eq!(format!("{:+}", shrunk), "-200MB");
+        assert_eq!(format!("{:.1}", ByteDelta::new(-1536)), "-1.5KiB");
+        assert_eq!(ByteDelta::ZERO.to_string(), "0B");
+        assert_eq!(format!("{:+}", ByteDelta::new(3)), "+3B");
+        assert_eq!(ByteDelta::new(i128::MIN).to_string(), "-16EiB");
+
+        assert_eq!(format!("{:>8}", shrunk), "  -200MB");
+        assert_eq!(format!("{:8}", shrunk), "  -200MB");
+        assert_eq!(format!("{:<8}|", shrunk), "-200MB  |");
+        assert_eq!(format!("{:*^10}", shrunk), "**-200MB**");
+        assert_eq!(format!("{:>#10.1}", ByteDelta::new(-1536)), "  -1.5 KiB");
+        assert_eq!(format!("{:+6}", ByteDelta::new(3)), "   +3B");
+        assert_eq!(format!("{:4}", shrunk), "-200MB");
+    }
+}
@@ -0,0 +1,298 @@
+use core::time::Duration;
+
+use crate::{ByteUnit, Error};
+use crate::parse::is_unit_name;
+use crate::byte_unit::{Rounding, precision, round_frac, promote};
+
+/// A rate of bytes per duration, such as `10MB/s`.
+///
+/// # Overview
+///
+/// A `ByteRate` pairs a [`ByteUnit`] with the [`Duration`] over which it was
+/// transferred. A `ByteRate` is constructed with [`ByteUnit::per()`],
+/// [`ByteRate::new()`], or by parsing a string. All conversions saturate: a
+/// rate over a zero duration is the maximum rate.
+///
+/// Equality is structural: `1MB` per second and `60MB` per minute are distinct
+/// `ByteRate`s. Compare [`ByteRate::per_second()`] values to compare rates.
+///
+/// # Parsing
+///
+/// A `ByteRate` is parsed from a byte unit, in the grammar accepted by
+/// [`ByteUnit`]'s `FromStr` implementation, followed by a `/` and a case
+/// insensitive time unit: `ns`, `us`, `ms`, `s`, `sec`, `min`, `h`, `hr`, `d`,
+/// or a singular or plural unit name from `nanosecond` to `day`. Whitespace is
+/// permitted around the `/`.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(not(any(feature = "default-si", feature = "default-iec")))] {
+/// use core::time::Duration;
+/// use ubyte::{ByteRate, ToByteUnit};
+///
+/// let rate = 5.mebibytes().per(Duration::from_secs(2));
+/// assert_eq!(rate.per_second(), 2.mebibytes() + 512.kibibytes());
+/// assert_eq!(rate.as_bytes_per_sec(), 2_621_440.0);
+/// assert_eq!(rate.to_string(), "2.50 MiB/s");
+/// assert_eq!(format!("{:.1}", rate), "2.5 MiB/s");
+///
+/// let rate: ByteRate = "1GiB/min".parse().unwrap();
+/// assert_eq!(rate, 1.gibibytes().per(Duration::from_secs(60)));
+///
+/// let rate: ByteRate = "10MB/s".parse().unwrap();
+/// assert_eq!(rate.per_second(), 10.megabytes());
+/// # }
+/// ```
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ByteRate {
+    bytes: ByteUnit,
+    duration: Duration,
+}
+
+impl ByteRate {
+    /// Constructs a `ByteRate` of `bytes` per `duration`. Equivalent to
+    /// [`ByteUnit::per()`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use core::time::Duration;
+    /// use ubyte::{ByteRate, ToByteUnit};
+    ///
+    /// let rate = ByteRate::new(5.kibibytes(), Duration::from_millis(500));
+    /// assert_eq!(rate.per_second(), 10.kibibytes());
+    /// ```
+    pub const fn new(bytes: ByteUnit, duration: Duration) -> ByteRate {
+        ByteRate { bytes, duration }
+    }
+
+    /// Returns the bytes transferred per [`ByteRate::duration()`].
+    pub const fn bytes(&self) -> ByteUnit {
+        self.bytes
+    }
+
+    /// Returns the duration over which [`ByteRate::bytes()`] are transferred.
+    pub const fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Returns the bytes transferred per second, rounding toward zero. Rates
+    /// over a zero duration or exceeding [`ByteUnit::max_value()`] saturate.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use core::time::Duration;
+    /// use ubyte::{ByteUnit, ToByteUnit};
+    ///
+    /// let rate = 1.megabytes().per(Duration::from_millis(1));
+    /// assert_eq!(rate.per_second(), 1.gigabytes());
+    ///
+    /// let rate = 90.bytes().per(Duration::from_secs(60));
+    /// assert_eq!(rate.per_second(), 1.bytes());
+    ///
+    /// let rate = 1.bytes().per(Duration::from_secs(0));
+    /// assert_eq!(rate.per_second(), ByteUnit::max_value());
+    /// ```
+    pub fn per_second(&self) -> ByteUnit {
+        match self.duration.as_nanos() {
+            0 => ByteUnit::max_value(),
+            nanos => ByteUnit::from(self.bytes.as_u128() * 1_000_000_000 / nanos),
+        }
+    }
+
+    /// Returns the bytes transferred per second as an `f64`. Rates over a zero
+    /// duration saturate to `ByteUnit::max_value()`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use core::time::Duration;
+    /// use ubyte::{ByteUnit, ToByteUnit};
+    ///
+    /// let rate = 90.bytes().per(Duration::from_secs(60));
+    /// assert_eq!(rate.as_bytes_per_sec(), 1.5);
+    ///
+    /// let rate = 1.bytes().per(Duration::from_secs(0));
+    /// assert_eq!(rate.as_bytes_per_sec(), u64::MAX as f64);
+    /// ```
+    pub fn as_bytes_per_sec(&self) -> f64 {
+        match self.duration.as_nanos() {
+            0 => ByteUnit::max_value().as_u64() as f64,
+            _ => self.bytes.as_u64() as f64 / self.duration.as_secs_f64(),
+        }
+    }
+}
+
+impl ByteUnit {
+    /// Returns the [`ByteRate`] of `self` per `duration`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use core::time::Duration;
+    /// use ubyte::ToByteUnit;
+    ///
+    /// let rate = 300.megabytes().per(Duration::from_secs(60));
+    /// assert_eq!(rate.per_second(), 5.megabytes());
+    /// assert_eq!(rate.to_string(), "5 MB/s");
+    /// ```
+    pub const fn per(self, duration: Duration) -> ByteRate {
+        ByteRate::new(self, duration)
+    }
+
+    /// Returns how long transferring `self` takes at `bytes_per_sec` bytes
+    /// per second, such as a [`ByteRate`].
+    ///
+    /// Transferring zero bytes, or transferring at an infinite rate, takes
+    /// [`Duration::ZERO`]. Transfers at a zero, negative, or NaN rate, or that
+    /// would take longer than [`Duration::MAX`], saturate to
+    /// `Duration::MAX`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use core::time::Duration;
+    /// use ubyte::ToByteUnit;
+    ///
+    /// let rate = 10.megabytes().per(Duration::from_secs(1));
+    /// assert_eq!(1.gigabytes().transfer_time(rate), Duration::from_secs(100));
+    /// assert_eq!(1.megabytes().transfer_time(4e6), Duration::from_millis(250));
+    ///
+    /// assert_eq!(1.megabytes().transfer_time(f64::INFINITY), Duration::ZERO);
+    /// assert_eq!(0.bytes().transfer_time(0.0), Duration::ZERO);
+    /// assert_eq!(1.bytes().transfer_time(0.0), Duration::MAX);
+    /// assert_eq!(1.gigabytes().transfer_time(1e-300), Duration::MAX);
+    /// ```
+    pub fn transfer_time<R: Into<f64>>(self, bytes_per_sec: R) -> Duration {
+        let rate = bytes_per_sec.into();
+        if self.0 == 0 || rate == f64::INFINITY {
+            return Duration::ZERO;
+        }
+
+        match rate > 0.0 {
+            true => Duration::try_from_secs_f64(self.0 as f64 / rate).unwrap_or(Duration::MAX),
+            false => Duration::MAX,
+        }
+    }
+}
+
+/// Converts into the bytes transferred per second as with
+/// [`ByteRate::as_bytes_per_sec()`].
+impl From<ByteRate> for f64 {
+    #[inline(always)]
+    fn from(rate: ByteRate) -> Self {
+        rate.as_bytes_per_sec()
+    }
+}
+
+/// Parses the time unit of a rate, returning its duration.
+fn parse_time_unit(s: &str) -> Option<Duration> {
+    const UNITS: &[(&[&str], &str, Duration)] = &[
+        (&["ns"], "nanosecond", Duration::from_nanos(1)),
+        (&["us"], "microsecond", Duration::from_micros(1)),
+        (&["ms"], "millisecond", Duration::from_millis(1)),
+        (&["s", "sec"], "second", Duration::from_secs(1)),
+        (&["min"], "minute", Duration::from_secs(60)),
+        (&["h", "hr"], "hour", Duration::from_secs(60 * 60)),
+        (&["d"], "day", Duration::from_secs(24 * 60 * 60)),
+    ];
+
+    UNITS.iter()
+        .find(|(abbrevs, name, _)| {
+            abbrevs.iter().any(|a| s.eq_ignore_ascii_case(a)) || is_unit_name(s, name)
+        })
+        .map(|(_, _, duration)| *duration)
+}
+
+impl core::str::FromStr for ByteRate {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let slash = match s.rfind('/') {
+            Some(i) => i,
+            None => return Err(Error::BadRate(s.len()..s.len())),
+        };
+
+        let bytes = s[..slash].trim_end().parse::<ByteUnit>()?;
+        let unit = &s[(slash + 1)..];
+        let start = slash + 1 + (unit.len() - unit.trim_start().len());
+        let duration = parse_time_unit(unit.trim())
+            .ok_or(Error::BadRate(start..s.len()))?;
+
+        Ok(bytes.per(duration))
+    }
+}
+
+/// Display the rate per second as with `ByteUnit`, but with a space between
+/// the value and the suffix, followed by `/s`.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(not(any(feature = "default-si", feature = "default-iec")))] {
+/// use core::time::Duration;
+/// use ubyte::ToByteUnit;
+///
+/// let second = Duration::from_secs(1);
+/// assert_eq!(10.megabytes().per(second).to_string(), "10 MB/s");
+/// assert_eq!(3.mebibytes().per(second).to_string(), "3 MiB/s");
+/// assert_eq!(7.bytes().per(second).to_string(), "7 B/s");
+/// assert_eq!(1.kibibytes().per(second * 3).to_string(), "341 B/s");
+/// assert_eq!(format!("{:.0}", (7.gibibytes() + 920.mebibytes()).per(second)), "8 GiB/s");
+/// # }
+/// ```
+impl core::fmt::Display for ByteRate {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let (whole, rem, suffix, unit) = self.per_second().int_repr();
+        let width = f.width().unwrap_or(0);
+        let p = precision(f);
+        match round_frac(rem, unit.as_u64(), p, Rounding::Standard) {
+            Some(frac) if rem != 0 && p > 0 => {
+                write!(f, "{:0width$}.{:0p$} {}/s", whole, frac, suffix, p = p, width = width)
+            }
+            Some(_) => write!(f, "{:0width$} {}/s", whole, suffix, width = width),
+            None => match promote(whole, unit.as_u64()) {
+                Some(next) => ByteUnit(next).per(Duration::from_secs(1)).fmt(f),
+                None => write!(f, "{:0width$} {}/s", whole as u128 + 1, suffix, width = width),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod rate_tests {
+    use core::time::Duration;
+
+    use crate::{ByteUnit, ToByteUnit};
+
+    #[test]
+    fn test_transfer_time() {
+        let second = Duration::from_secs(1);
+        assert_eq!(1.mebibytes().transfer_time(1.kibibytes().per(second)), second * 1024);
+        assert_eq!(1.mebibytes().transfer_time(1.mebibytes().per(second * 4)), second * 4);
+        assert_eq!(1.mebibytes().transfer_time(1.mebibytes().per(Duration::ZERO)), Duration::ZERO);
+        assert_eq!(1.kilobytes().transfer_time(500u32), second * 2);
+        assert_eq!(1.kilobytes().transfer_time(2000.0f32), second / 2);
+
+        assert_eq!(ByteUnit::max_value().transfer_time(f64::INFINITY), Duration::ZERO);
+        assert_eq!(ByteUnit::max_value().transfer_time(f64::MIN_POSITIVE), Duration::MAX);
+        assert_eq!(ByteUnit::max_value().transfer_time(f64::MAX), Duration::ZERO);
+        assert_eq!(1.bytes().transfer_time(-1.0), Duration::MAX);
+        assert_eq!(1.bytes().transfer_time(f64::NEG_INFINITY), Duration::MAX);
+        assert_eq!(1.bytes().transfer_time(f64::NAN), Duration::MAX);
+        assert_eq!(0.bytes().transfer_time(f64::NAN), Duration::ZERO);
+    }
+
+    #[test]
+    #[cfg_attr(any(feature = "default-si", feature = "default-iec"), ignore = "expects Auto")]
+    fn test_display_rounding_carry() {
+        extern crate std;
+        use std::format;
+
+        let second = Duration::from_secs(1);
+        assert_eq!(format!("{}", (100.kibibytes() - 1).per(second)), "100 KiB/s");
+        assert_eq!(format!("{}", ByteUnit::max_value().per(second)), "16 EiB/s");
+    }
+}
@@ -10,19 +10,34 @@
 /// [`ToByteUnit`] provides human-friendly methods on all integer types for
 /// converting into a `ByteUnit`: [`512.megabytes()`](ToByteUnit::megabytes).
 ///
+/// Units larger than the exbibyte -- [`ByteUnit::ZB`], [`ByteUnit::ZiB`],
+/// [`ByteUnit::YB`], and [`ByteUnit::YiB`] -- exceed the maximum value of a
+/// `ByteUnit`. Their constructors saturate for any non-zero value, and their
+/// associated constants are thus all [`ByteUnit::max_value()`]. The parser,
+/// however, knows their exact size: `"0.001 ZB"` parses as `1EB`.
+///
 /// # Parsing
 ///
-/// `ByteUnit` implements `FromStr` for parsing byte unit strings into a
-/// `ByteUnit`. The grammar accepted by the parser is:
+/// `ByteUnit` implements `FromStr`, also available as [`ByteUnit::parse()`],
+/// for parsing byte unit strings into a `ByteUnit`. The grammar accepted by the parser is:
 ///
 /// ```ebnf
-/// byte_unit := uint+ ('.' uint+)? WHITESPACE* suffix
+/// byte_unit := '+'? (decimal | radix) WHITESPACE? suffix
 ///
+/// decimal := digits ('.' digits)? exponent?
+/// digits := uint+ ('_' uint+)*
 /// uint := '0'..'9'
-/// suffix := case insensitive SI byte unit suffix ('b' to 'eib')
-/// WHITESPACE := the ' ' character
+/// exponent := ('e' | 'E') ('+' | '-')? uint+
+/// radix := '0x' ('0'..'9' | 'a'..'f' | 'A'..'F')+ | '0o' '0'..'7'+ | '0b' '0'..'1'+
+/// suffix := case insensitive SI byte unit suffix ('b' to 'yib') or singular
+///           or plural unit name ('byte' to 'yobibytes')
+/// WHITESPACE := ' '+ | the no-break space, U+00A0
 /// ```
 ///
+/// Hexadecimal digits are consumed greedily, so a suffix beginning with a
+/// hexadecimal digit, such as `B` or `EiB`, must be separated from a `0x`
+/// number by whitespace: `0x10B` is `0x10b` bytes while `0x10 B` is `16` bytes.
+///
 /// ```rust
 /// use ubyte::{ByteUnit, ToByteUnit};
 ///
@@ -40,6 +55,21 @@
 ///
 /// let some_mb: ByteUnit = "20.5MB".parse().unwrap();
 /// assert_eq!(some_mb, 20.megabytes() + 500.kilobytes());
+///
+/// let one_tb: ByteUnit = "1e6 MB".parse().unwrap();
+/// assert_eq!(one_tb, 1.terabytes());
+///
+/// let some_kib: ByteUnit = "9.5e3KiB".parse().unwrap();
+/// assert_eq!(some_kib, 9500.kibibytes());
+///
+/// let five_mb: ByteUnit = "5 megabytes".parse().unwrap();
+/// assert_eq!(five_mb, 5.megabytes());
+///
+/// let one_mib: ByteUnit = "1_048_576".parse().unwrap();
+/// assert_eq!(one_mib, 1.mebibytes());
+///
+/// let page: ByteUnit = "0x1000".parse().unwrap();
+/// assert_eq!(page, 4.kibibytes());
 /// ```
 ///
 /// # (De)serialization
@@ -47,8 +77,11 @@
 /// With the `serde` feaure enabled (disabled by default), `ByteUnit` implements
 /// [`Deserialize`](#impl-Deserialize<%27de>) from strings, using the same
 /// grammar as the `FromStr` implementation, defined above, as well as all
-/// integer types. The [`Serialize`](struct.ByteUnit.html#impl-Serialize)
-/// implementation serializes into a `u64`.
+/// integer types and non-negative floats, which saturate and truncate as with
+/// [`ByteUnit::from_f64()`]. The [`Serialize`](struct.ByteUnit.html#impl-Serialize)
+/// implementation serializes into a `u64`. To serialize into a human-readable
+/// string like `"512KiB"` instead, use `#[serde(with =
+/// "ubyte::serde::as_str")]`.
 ///
 /// # Example
 ///
@@ -80,99 +113,175 @@
 /// assert_eq!(too_small, 0);
 /// ```
 #[repr(transparent)]
-#[derive(Debug, Default, Copy, Clone, Eq, Hash, Ord)]
+#[derive(Default, Copy, Clone, Eq, Hash, Ord)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+#[cfg_attr(feature = "rkyv", rkyv(derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)))]
 pub struct ByteUnit(pub(crate) u64);
 
+/// The components of a minimal unit representation, `(whole, rem, suffix,
+/// unit)`, with the remainder `rem` counted in the base unit rather than as a
+/// fraction of `unit` so that it can be displayed without floating point.
+pub(crate) type IntRepr<T> = (u64, u64, &'static str, T);
+
+/// Converts an `IntRepr` into the `(whole, frac, suffix, unit)` returned by
+/// `repr()` methods.
+pub(crate) fn float_repr<T: Copy + Into<u64>>(repr: IntRepr<T>) -> (u64, f64, &'static str, T) {
+    let (whole, rem, suffix, unit) = repr;
+    (whole, rem as f64 / unit.into() as f64, suffix, unit)
+}
+
 macro_rules! rem_and_suffix {
-    ($n:expr => $(($isuffix:ident, $suffix:ident)),+ $or_else:ident) => {
+    ($T:ident, $n:expr => $(($isuffix:ident, $suffix:ident)),+ $or_else:ident) => {
         loop {
             $(
-                let i_val = ByteUnit::$isuffix.as_u64();
-                let s_val = ByteUnit::$suffix.as_u64();
+                let i_val = $T::$isuffix.as_u64();
+                let s_val = $T::$suffix.as_u64();
 
                 if $n >= s_val {
                     let (u_val, unit, string) = if $n % s_val >= i_val - s_val {
-                        (i_val, ByteUnit::$isuffix, stringify!($isuffix))
+                        (i_val, $T::$isuffix, stringify!($isuffix))
                     } else {
-                        (s_val, ByteUnit::$suffix, stringify!($suffix))
+                        (s_val, $T::$suffix, stringify!($suffix))
                     };
 
-                    break ($n / u_val, ($n % u_val) as f64 / u_val as f64, string, unit)
+                    break ($n / u_val, $n % u_val, string, unit)
                 }
             )+
 
-            break ($n, 0f64, stringify!($or_else), ByteUnit::$or_else)
+            break ($n, 0, stringify!($or_else), $T::$or_else)
         }
     };
-}
 
-macro_rules! const_if {
-    ($cond:expr, $on_true:expr, $on_false:expr) => (
-        [$on_false, $on_true][$cond as usize]
-    )
+    ($T:ident, $n:expr => $($suffix:ident),+ ; $or_else:ident) => {
+        loop {
+            $(
+                let u_val = $T::$suffix.as_u64();
+                if $n >= u_val {
+                    break ($n / u_val, $n % u_val, stringify!($suffix), $T::$suffix)
+                }
+            )+
+
+            break ($n, 0, stringify!($or_else), $T::$or_else)
+        }
+    };
 }
 
 macro_rules! constructor_fns {
-    ($($sstr:expr, $nstr:expr, $example:expr, $suffix:ident, $name:ident = $size:expr),*) => (
+    (
+        $T:ident, $what:expr =>
+        $($sstr:expr, $nstr:expr, $example:expr, $suffix:ident, $name:ident = $size:expr),*
+    ) => (
         $(
-            /// Number of bytes in 1
+            #[doc = concat!("Number of ", $what, " in 1")]
             #[doc = $sstr]
             /// (`
             #[doc = $nstr]
             /// `).
             #[allow(non_upper_case_globals)]
-            pub const $suffix: ByteUnit = ByteUnit::$name(1);
+            pub const $suffix: $T = $T::$name(1);
         )*
 
         $(
-            /// Constructs a `ByteUnit` representing `n`
+            #[doc = concat!("Constructs a `", stringify!($T), "` representing `n`")]
             #[doc = $sstr]
             /// .
             ///
             /// # Example
             ///
             /// ```rust
-            /// # use ubyte::ByteUnit;
+            #[doc = concat!("# use ubyte::", stringify!($T), ";")]
             #[doc = $example]
             /// ```
             #[allow(non_snake_case)]
-            pub const fn $name(n: u64) -> ByteUnit {
-                let size: u64 = $size;
-                let v = const_if!(n as u128 * size as u128 > u64::max_value() as u128,
-                    ByteUnit::max_value().as_u128(),
-                    n as u128 * size as u128
-                );
-
-                ByteUnit(v as u64)
+            pub const fn $name(n: u64) -> $T {
+                let size: u128 = $size;
+                match (n as u128).checked_mul(size) {
+                    Some(v) if v <= u64::MAX as u128 => $T(v as u64),
+                    _ => $T::max_value(),
+                }
             }
         )*
     );
 
-    ($($suffix:ident, $name:ident = $size:expr),* $(,)?) => (
-        constructor_fns!($(
+    ($T:ident, $what:expr, $units:ident =>
+        $($suffix:ident = $long:expr, $name:ident = $size:expr),* $(,)?
+    ) => (
+        constructor_fns!($T, $what => $($suffix, $name = $size),*);
+
+        /// Every unit above, its suffix, and its singular long name.
+        const $units: &'static [($T, &'static str, &'static str)] = &[
+            $(($T::$suffix, stringify!($suffix), $long)),*
+        ];
+    );
+
+    ($T:ident, $what:expr => $($suffix:ident, $name:ident = $size:expr),* $(,)?) => (
+        constructor_fns!($T, $what => $(
             stringify!($suffix), stringify!($size), concat!(
-                "assert_eq!(ByteUnit::", stringify!($name), "(10), ",
-                "10 * ByteUnit::", stringify!($suffix), ");"
+                "assert_eq!(", stringify!($T), "::", stringify!($name), "(10), ",
+                "10 * ", stringify!($T), "::", stringify!($suffix), ");"
             ), $suffix, $name = $size
         ),*);
     )
 }
 
 impl ByteUnit {
-    constructor_fns! {
-        B, Byte = 1,
-        kB, Kilobyte = 1_000,
-        KiB, Kibibyte = 1 << 10,
-        MB, Megabyte = 1_000_000,
-        MiB, Mebibyte = 1 << 20,
-        GB, Gigabyte = 1_000_000_000,
-        GiB, Gibibyte = 1 << 30,
-        TB, Terabyte = 1_000_000_000_000,
-        TiB, Tebibyte = 1 << 40,
-        PB, Petabyte = 1_000_000_000_000_000,
-        PiB, Pebibyte = 1 << 50,
-        EB, Exabyte = 1_000_000_000_000_000_000,
-        EiB, Exbibyte = 1  << 60,
+    constructor_fns! { ByteUnit, "bytes", UNITS =>
+        B = "byte", Byte = 1,
+        kB = "kilobyte", Kilobyte = 1_000,
+        KiB = "kibibyte", Kibibyte = 1 << 10,
+        MB = "megabyte", Megabyte = 1_000_000,
+        MiB = "mebibyte", Mebibyte = 1 << 20,
+        GB = "gigabyte", Gigabyte = 1_000_000_000,
+        GiB = "gibibyte", Gibibyte = 1 << 30,
+        TB = "terabyte", Terabyte = 1_000_000_000_000,
+        TiB = "tebibyte", Tebibyte = 1 << 40,
+        PB = "petabyte", Petabyte = 1_000_000_000_000_000,
+        PiB = "pebibyte", Pebibyte = 1 << 50,
+        EB = "exabyte", Exabyte = 1_000_000_000_000_000_000,
+        EiB = "exbibyte", Exbibyte = 1  << 60,
+    }
+
+    // These units exceed `u64::MAX` and thus saturate for any non-zero value.
+    constructor_fns! { ByteUnit, "bytes" =>
+        ZB, Zettabyte = 1_000_000_000_000_000_000_000,
+        ZiB, Zebibyte = 1 << 70,
+        YB, Yottabyte = 1_000_000_000_000_000_000_000_000,
+        YiB, Yobibyte = 1 << 80,
+    }
+
+    /// Zero bytes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ubyte::ByteUnit;
+    /// assert_eq!(ByteUnit::ZERO, 0);
+    /// assert_eq!(ByteUnit::ZERO, ByteUnit::default());
+    /// ```
+    pub const ZERO: ByteUnit = ByteUnit(0);
+
+    /// The minimum value of bytes representable by `ByteUnit`: zero bytes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ubyte::ByteUnit;
+    /// assert_eq!(ByteUnit::MIN, ByteUnit::ZERO);
+    /// ```
+    pub const MIN: ByteUnit = ByteUnit::ZERO;
+
+    /// Constructs a `ByteUnit` representing `bytes` bytes. Equivalent to
+    /// [`ByteUnit::Byte()`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ubyte::ByteUnit;
+    /// const PAGE: ByteUnit = ByteUnit::new(4096);
+    /// assert_eq!(PAGE, 4 * ByteUnit::KiB);
+    /// ```
+    pub const fn new(bytes: u64) -> ByteUnit {
+        ByteUnit(bytes)
     }
 
     /// The maximum value of bytes representable by `ByteUnit`.
@@ -181,10 +290,10 @@ impl ByteUnit {
     ///
     /// ```rust
     /// # use ubyte::ByteUnit;
-    /// assert_eq!(ByteUnit::max_value(), u64::max_value());
+    /// assert_eq!(ByteUnit::max_value(), u64::MAX);
     /// ```
     pub const fn max_value() -> ByteUnit {
-        ByteUnit(u64::max_value())
+        ByteUnit(u64::MAX)
     }
 
     /// Returns the value of bytes represented by `self` as a `u64`.
@@ -219,179 +328,1448 @@ impl ByteUnit {
         self.0 as u128
     }
 
-    /// Returns the components of the minimal unit representation of `self`.
+    /// Returns the number of bytes in `self` as a `u64` in little-endian byte
+    /// order, exactly as [`u64::to_le_bytes()`]. This encoding is stable and
+    /// is inverted by [`ByteUnit::from_le_bytes()`].
     ///
-    /// The "minimal unit representation" is the representation that maximizes
-    /// the SI-unit while minimizing the whole part of the value. For example,
-    /// `1024.bytes()` is minimally represented by `1KiB`, while `1023.bytes()`
-    /// is minimally represented by `1.023kB`.
+    /// # Example
     ///
-    /// The four components returned, in tuple-order, are:
-    ///   * `whole` - the whole part of the minimal representation.
-    ///   * `frac` - the fractional part of the minimal representation.
-    ///   * `suffix` - the suffix of the minimal representation.
-    ///   * `unit` - the `1`-unit of the minimal representation.
+    /// ```rust
+    /// use ubyte::{ByteUnit, ToByteUnit};
     ///
-    /// Succinctly, this is: `(whole, frac, suffix, unit)`. Observe that `(whole
-    /// + frac) * unit` reconstructs the original value.
+    /// let bytes = 1.kibibytes().to_le_bytes();
+    /// assert_eq!(bytes, [0, 4, 0, 0, 0, 0, 0, 0]);
+    /// assert_eq!(ByteUnit::from_le_bytes(bytes), 1.kibibytes());
+    /// ```
+    pub const fn to_le_bytes(self) -> [u8; 8] {
+        self.0.to_le_bytes()
+    }
+
+    /// Returns the number of bytes in `self` as a `u64` in big-endian byte
+    /// order, exactly as [`u64::to_be_bytes()`]. This encoding is stable and
+    /// is inverted by [`ByteUnit::from_be_bytes()`].
     ///
     /// # Example
     ///
     /// ```rust
     /// use ubyte::{ByteUnit, ToByteUnit};
     ///
-    /// let value = 2.mebibytes() + 512.kibibytes();
-    /// assert_eq!(value.to_string(), "2.50MiB");
+    /// let bytes = 1.kibibytes().to_be_bytes();
+    /// assert_eq!(bytes, [0, 0, 0, 0, 0, 0, 4, 0]);
+    /// assert_eq!(ByteUnit::from_be_bytes(bytes), 1.kibibytes());
+    /// ```
+    pub const fn to_be_bytes(self) -> [u8; 8] {
+        self.0.to_be_bytes()
+    }
+
+    /// Constructs a `ByteUnit` from a `u64` number of bytes in little-endian
+    /// byte order, as returned by [`ByteUnit::to_le_bytes()`].
     ///
-    /// let (whole, frac, suffix, unit) = value.repr();
-    /// assert_eq!(whole, 2);
-    /// assert_eq!(frac, 0.5);
-    /// assert_eq!(suffix, "MiB");
-    /// assert_eq!(unit, ByteUnit::MiB);
+    /// # Example
     ///
-    /// let reconstructed = (whole as f64 + frac) * unit.as_u64() as f64;
-    /// assert_eq!(reconstructed as u64, value);
+    /// ```rust
+    /// use ubyte::{ByteUnit, ToByteUnit};
+    ///
+    /// const PAGE: ByteUnit = ByteUnit::from_le_bytes([0, 16, 0, 0, 0, 0, 0, 0]);
+    /// assert_eq!(PAGE, 4.kibibytes());
     /// ```
-    pub fn repr(self) -> (u64, f64, &'static str, ByteUnit) {
-        rem_and_suffix! { self.as_u64() =>
-            (EiB, EB), (TiB, TB), (GiB, GB), (MiB, MB), (KiB, kB) B
-        }
+    pub const fn from_le_bytes(bytes: [u8; 8]) -> ByteUnit {
+        ByteUnit(u64::from_le_bytes(bytes))
     }
-}
 
-impl From<ByteUnit> for u64 {
-    #[inline(always)]
-    fn from(v: ByteUnit) -> Self {
-        v.as_u64()
+    /// Constructs a `ByteUnit` from a `u64` number of bytes in big-endian byte
+    /// order, as returned by [`ByteUnit::to_be_bytes()`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteUnit, ToByteUnit};
+    ///
+    /// const PAGE: ByteUnit = ByteUnit::from_be_bytes([0, 0, 0, 0, 0, 0, 16, 0]);
+    /// assert_eq!(PAGE, 4.kibibytes());
+    /// ```
+    pub const fn from_be_bytes(bytes: [u8; 8]) -> ByteUnit {
+        ByteUnit(u64::from_be_bytes(bytes))
     }
-}
 
-impl From<ByteUnit> for u128 {
-    #[inline(always)]
-    fn from(v: ByteUnit) -> Self {
-        v.as_u128()
+    /// Constructs a `ByteUnit` from a number of bits, rounding toward zero
+    /// when `bits` isn't a whole number of bytes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ubyte::ByteUnit;
+    /// assert_eq!(ByteUnit::from_bits(256), 32);
+    /// assert_eq!(ByteUnit::from_bits(15), 1);
+    /// assert_eq!(ByteUnit::from_bits(7), 0);
+    /// ```
+    pub const fn from_bits(bits: u64) -> ByteUnit {
+        ByteUnit(bits / 8)
     }
-}
 
-macro_rules! impl_from_int_unknown {
-    ($T:ty) => (
-        impl From<$T> for ByteUnit {
-            #[inline(always)]
-            fn from(value: $T) -> Self {
-                if core::mem::size_of::<$T>() <= core::mem::size_of::<i64>() {
-                    ByteUnit::from(value as i64)
-                } else if value <= i64::max_value() as $T {
-                    ByteUnit::from(value as i64)
-                } else {
-                    ByteUnit::max_value()
-                }
-            }
-        }
-    )
-}
+    /// Constructs a `ByteUnit` from a number of bits, rounding up to the next
+    /// whole byte when `bits` isn't a whole number of bytes. This is the
+    /// number of bytes needed to _hold_ `bits`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ubyte::ByteUnit;
+    /// assert_eq!(ByteUnit::from_bits_ceil(256), 32);
+    /// assert_eq!(ByteUnit::from_bits_ceil(15), 2);
+    /// assert_eq!(ByteUnit::from_bits_ceil(7), 1);
+    /// assert_eq!(ByteUnit::from_bits_ceil(0), 0);
+    /// ```
+    pub const fn from_bits_ceil(bits: u64) -> ByteUnit {
+        ByteUnit(bits.div_ceil(8))
+    }
 
-macro_rules! impl_from_uint_unknown {
-    ($T:ty) => (
-        impl From<$T> for ByteUnit {
-            #[inline(always)]
-            fn from(value: $T) -> Self {
-                if core::mem::size_of::<$T>() <= core::mem::size_of::<u64>() {
-                    ByteUnit(value as u64)
-                } else if value <= u64::max_value() as $T {
-                    ByteUnit(value as u64)
-                } else {
-                    ByteUnit::max_value()
-                }
-            }
-        }
-    )
-}
+    /// Returns the number of bits in `self`, saturating at `u64::MAX`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ubyte::ByteUnit;
+    /// assert_eq!(ByteUnit::Byte(32).as_bits(), 256);
+    /// assert_eq!(ByteUnit::Kibibyte(1).as_bits(), 8192);
+    /// assert_eq!(ByteUnit::max_value().as_bits(), u64::MAX);
+    /// ```
+    pub const fn as_bits(self) -> u64 {
+        self.0.saturating_mul(8)
+    }
 
-macro_rules! impl_from_unsigned {
-    ($T:ty) => (
-        impl From<$T> for ByteUnit {
-            #[inline(always)] fn from(v: $T) -> Self { ByteUnit(v as u64) }
-        }
-    )
-}
+    /// Returns `true` if `self` is zero bytes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ubyte::ByteUnit;
+    /// assert!(ByteUnit::ZERO.is_zero());
+    /// assert!(!ByteUnit::B.is_zero());
+    /// ```
+    pub const fn is_zero(self) -> bool {
+        self.0 == 0
+    }
 
-macro_rules! impl_from_signed {
-    ($T:ty) => (
-        impl From<$T> for ByteUnit {
-            #[inline(always)] fn from(v: $T) -> Self {
-                ByteUnit(core::cmp::max(v, 0) as u64)
-            }
-        }
-    )
-}
+    /// Returns `true` if `self` is a power of two bytes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ubyte::ByteUnit;
+    /// assert!(ByteUnit::MiB.is_power_of_two());
+    /// assert!(ByteUnit::B.is_power_of_two());
+    /// assert!(!ByteUnit::MB.is_power_of_two());
+    /// assert!(!ByteUnit::ZERO.is_power_of_two());
+    /// ```
+    pub const fn is_power_of_two(self) -> bool {
+        self.0.is_power_of_two()
+    }
 
-impl_from_unsigned!(u8);
-impl_from_unsigned!(u16);
-impl_from_unsigned!(u32);
-impl_from_unsigned!(u64);
+    /// Returns the smallest power of two bytes greater than or equal to
+    /// `self`, saturating at [`ByteUnit::max_value()`] when that power of two
+    /// exceeds `u64::MAX`. As with `u64`, the next power of two of `0` is `1`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteUnit, ToByteUnit};
+    ///
+    /// assert_eq!(1.megabytes().next_power_of_two(), 1.mebibytes());
+    /// assert_eq!(1.mebibytes().next_power_of_two(), 1.mebibytes());
+    /// assert_eq!(0.bytes().next_power_of_two(), 1.bytes());
+    /// assert_eq!((8.exbibytes() + 1).next_power_of_two(), ByteUnit::max_value());
+    /// ```
+    pub const fn next_power_of_two(self) -> ByteUnit {
+        match self.0.checked_next_power_of_two() {
+            Some(value) => ByteUnit(value),
+            None => ByteUnit::max_value(),
+        }
+    }
 
-impl_from_signed!(i8);
-impl_from_signed!(i16);
-impl_from_signed!(i32);
-impl_from_signed!(i64);
+    /// Returns the value of bytes represented by `self` as an `f64`.
+    ///
+    /// Values larger than `2^53` are rounded to the nearest representable
+    /// `f64`, which is close enough for display and statistics.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ubyte::ByteUnit;
+    /// assert_eq!(ByteUnit::Megabyte(42).as_f64(), 42_000_000.0);
+    /// assert_eq!(ByteUnit::max_value().as_f64(), u64::MAX as f64);
+    /// ```
+    pub const fn as_f64(self) -> f64 {
+        self.0 as f64
+    }
 
-impl_from_uint_unknown!(usize);
-impl_from_uint_unknown!(u128);
-impl_from_int_unknown!(isize);
-impl_from_int_unknown!(i128);
+    /// Returns the value of bytes represented by `self` as an `f32`.
+    ///
+    /// An `f32` has only 24 bits of precision: values larger than `2^24`, or
+    /// `16MiB`, are rounded to the nearest representable `f32` and may thus
+    /// lose precision.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ubyte::ByteUnit;
+    /// assert_eq!(ByteUnit::Kilobyte(42).as_f32(), 42_000.0);
+    /// assert_eq!(ByteUnit::Mebibyte(16).as_f32(), 16_777_216.0);
+    /// assert_eq!(ByteUnit::Byte(16_777_217).as_f32(), 16_777_216.0);
+    /// ```
+    pub const fn as_f32(self) -> f32 {
+        self.0 as f32
+    }
 
-macro_rules! helper_fn {
-    ($kindstr:expr, $name:ident = $kind:ident) => (
-        /// Converts `self` to a `ByteUnit` representing `self`
-        #[doc = $kindstr]
-        /// .
-        #[inline(always)]
-        fn $name(self) -> ByteUnit {
-            self.bytes() * ByteUnit::$kind
-        }
-    );
+    /// Returns the smaller of `self` and `other`. Unlike [`Ord::min()`], this
+    /// method is `const`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ubyte::ByteUnit;
+    /// const SMALLER: ByteUnit = ByteUnit::KiB.min(ByteUnit::kB);
+    /// assert_eq!(SMALLER, ByteUnit::kB);
+    /// ```
+    pub const fn min(self, other: ByteUnit) -> ByteUnit {
+        if self.0 <= other.0 { self } else { other }
+    }
 
-    ($name:ident = $kind:ident) => (
-        helper_fn!(stringify!($kind), $name = $kind);
-    )
-}
+    /// Returns the larger of `self` and `other`. Unlike [`Ord::max()`], this
+    /// method is `const`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ubyte::ByteUnit;
+    /// const LARGER: ByteUnit = ByteUnit::KiB.max(ByteUnit::kB);
+    /// assert_eq!(LARGER, ByteUnit::KiB);
+    /// ```
+    pub const fn max(self, other: ByteUnit) -> ByteUnit {
+        if self.0 >= other.0 { self } else { other }
+    }
 
-/// Extension trait for conversion from integer types to [`ByteUnit`].
-///
-/// The `ToByteUnit` trait provides methods on integer types that convert the
-/// integer type into the [`ByteUnit`] unit represented by the method name. To
-/// use the trait, simply import it. The trait is implemented for all integer
-/// types.
-///
-/// As with all other `ByteUnit` operations, conversions saturate.
-///
-/// # Example
-///
-/// ```rust
-/// use ubyte::ToByteUnit;
-///
-/// assert_eq!(512.kilobytes(), 512000.bytes());
-/// assert_eq!(512.kibibytes(), 524288.bytes());
-/// assert_eq!(512.kilobytes(), 512 * 1.kilobytes());
-///
-/// assert_eq!(1000.bytes(), 1.kilobytes());
-/// assert_eq!(1000.bytes() + 24, 1.kibibytes());
-/// assert_eq!(2048.mebibytes(), 2.gibibytes());
-///
-/// assert!(2.megabytes() + 500.kilobytes() > 2.mebibytes());
-/// assert!(2.pebibytes() > 2.petabytes());
-///
-/// // As with other `ByteUnit` operations, conversions saturate.
-/// assert_eq!((1 << 10).exbibytes(), (1 << 20).exbibytes());
-/// ```
-pub trait ToByteUnit: Into<ByteUnit> {
-    /// Converts `self` to a `ByteUnit` representing `self` bytes.
-    #[inline(always)]
-    fn bytes(self) -> ByteUnit {
-        self.into()
+    /// Restricts `self` to the interval `[lo, hi]`: returns `lo` if `self` is
+    /// less than `lo`, `hi` if `self` is greater than `hi`, and `self`
+    /// otherwise. Unlike [`Ord::clamp()`], this method is `const`.
+    ///
+    /// In debug builds, panics if `lo > hi`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteUnit, ToByteUnit};
+    ///
+    /// const QUOTA: ByteUnit = ByteUnit::Gibibyte(5).clamp(ByteUnit::MiB, ByteUnit::GiB);
+    /// assert_eq!(QUOTA, 1.gibibytes());
+    ///
+    /// assert_eq!(1.bytes().clamp(1.kibibytes(), 1.gibibytes()), 1.kibibytes());
+    /// assert_eq!(3.mebibytes().clamp(1.kibibytes(), 1.gibibytes()), 3.mebibytes());
+    /// ```
+    pub const fn clamp(self, lo: ByteUnit, hi: ByteUnit) -> ByteUnit {
+        debug_assert!(lo.0 <= hi.0, "ByteUnit::clamp(): `lo` must be less than or equal to `hi`");
+        self.max(lo).min(hi)
     }
 
-    helper_fn!(kilobytes = kB);
+    /// Returns `self` capped at `max`: `max` if `self` is greater than `max`
+    /// and `self` otherwise. This is [`ByteUnit::min()`] named for chaining.
+    ///
+    /// Unlike `ByteUnit`'s arithmetic operators, `max` must be a `ByteUnit`
+    /// rather than any `Into<ByteUnit>` so that this method can be `const`.
+    /// Convert other values first, e.g. with `.into()` or [`ToByteUnit`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteUnit, ToByteUnit};
+    ///
+    /// let limit = 1.gibibytes();
+    /// assert_eq!(5.gibibytes().capped_at(limit), limit);
+    /// assert_eq!(3.mebibytes().capped_at(limit), 3.mebibytes());
+    /// assert_eq!(5000.bytes().capped_at(4096u32.into()), 4096);
+    ///
+    /// const CAPPED: ByteUnit = ByteUnit::Gibibyte(5).capped_at(ByteUnit::GiB);
+    /// assert_eq!(CAPPED, ByteUnit::GiB);
+    /// ```
+    pub const fn capped_at(self, max: ByteUnit) -> ByteUnit {
+        self.min(max)
+    }
+
+    /// Returns `self` raised to at least `min`: `min` if `self` is less than
+    /// `min` and `self` otherwise. This is [`ByteUnit::max()`] named for
+    /// chaining.
+    ///
+    /// As with [`ByteUnit::capped_at()`], `min` must be a `ByteUnit` so that
+    /// this method can be `const`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteUnit, ToByteUnit};
+    ///
+    /// let (page, limit) = (4.kibibytes(), 1.gibibytes());
+    /// assert_eq!(1.bytes().at_least(page).capped_at(limit), page);
+    /// assert_eq!(3.mebibytes().at_least(page).capped_at(limit), 3.mebibytes());
+    /// assert_eq!(5.gibibytes().at_least(page).capped_at(limit), limit);
+    ///
+    /// const RAISED: ByteUnit = ByteUnit::B.at_least(ByteUnit::KiB);
+    /// assert_eq!(RAISED, ByteUnit::KiB);
+    /// ```
+    pub const fn at_least(self, min: ByteUnit) -> ByteUnit {
+        self.max(min)
+    }
+
+    /// Compares `self` to a raw number of `bytes`. Unlike the generic
+    /// `PartialOrd` implementation, the operand type is fixed, so no type
+    /// inference is needed, and this method is `const`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use core::cmp::Ordering;
+    /// use ubyte::ToByteUnit;
+    ///
+    /// let len: u32 = 1000;
+    /// assert_eq!(1.kibibytes().cmp_bytes(len.into()), Ordering::Greater);
+    /// assert_eq!(1.kilobytes().cmp_bytes(len.into()), Ordering::Equal);
+    /// assert_eq!(1.bytes().cmp_bytes(len.into()), Ordering::Less);
+    /// ```
+    pub const fn cmp_bytes(self, bytes: u64) -> core::cmp::Ordering {
+        if self.0 < bytes {
+            core::cmp::Ordering::Less
+        } else if self.0 > bytes {
+            core::cmp::Ordering::Greater
+        } else {
+            core::cmp::Ordering::Equal
+        }
+    }
+
+    /// Returns `true` if `self` is exactly `bytes` bytes. Unlike the generic
+    /// `PartialEq` implementation, the operand type is fixed, so no type
+    /// inference is needed, and this method is `const`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteUnit, ToByteUnit};
+    ///
+    /// assert!(1.kibibytes().eq_bytes(1024));
+    /// assert!(!1.kibibytes().eq_bytes(1000));
+    ///
+    /// const IS_PAGE: bool = ByteUnit::Kibibyte(4).eq_bytes(4096);
+    /// assert!(IS_PAGE);
+    /// ```
+    pub const fn eq_bytes(self, bytes: u64) -> bool {
+        self.0 == bytes
+    }
+
+    /// Compares `self` to `other` exactly as the `Ord` implementation does but
+    /// in a `const` context.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use core::cmp::Ordering;
+    /// use ubyte::ByteUnit;
+    ///
+    /// const CHUNK: ByteUnit = ByteUnit::Kibibyte(64);
+    /// const ORDER: Ordering = CHUNK.const_cmp(ByteUnit::MiB);
+    /// assert_eq!(ORDER, Ordering::Less);
+    /// assert_eq!(ORDER, CHUNK.cmp(&ByteUnit::MiB));
+    /// ```
+    pub const fn const_cmp(self, other: ByteUnit) -> core::cmp::Ordering {
+        self.cmp_bytes(other.0)
+    }
+
+    /// Returns `true` if `self == other` in a `const` context.
+    pub const fn const_eq(self, other: ByteUnit) -> bool {
+        self.0 == other.0
+    }
+
+    /// Returns `true` if `self < other` in a `const` context.
+    pub const fn const_lt(self, other: ByteUnit) -> bool {
+        self.0 < other.0
+    }
+
+    /// Returns `true` if `self <= other` in a `const` context.
+    pub const fn const_le(self, other: ByteUnit) -> bool {
+        self.0 <= other.0
+    }
+
+    /// Returns `true` if `self > other` in a `const` context.
+    pub const fn const_gt(self, other: ByteUnit) -> bool {
+        self.0 > other.0
+    }
+
+    /// Returns `true` if `self >= other` in a `const` context, as in a static
+    /// assertion.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::ByteUnit;
+    ///
+    /// const CHUNK: ByteUnit = ByteUnit::Kibibyte(64);
+    /// const _: () = assert!(CHUNK.const_ge(ByteUnit::KiB));
+    /// const _: () = assert!(CHUNK.const_lt(ByteUnit::MiB));
+    /// const _: () = assert!(!CHUNK.const_eq(ByteUnit::KiB));
+    /// ```
+    ///
+    /// A failed assertion fails to compile:
+    ///
+    /// ```rust,compile_fail
+    /// use ubyte::ByteUnit;
+    ///
+    /// const CHUNK: ByteUnit = ByteUnit::Byte(512);
+    /// const _: () = assert!(CHUNK.const_ge(ByteUnit::KiB));
+    /// ```
+    pub const fn const_ge(self, other: ByteUnit) -> bool {
+        self.0 >= other.0
+    }
+
+    /// Returns an iterator over every `ByteUnit` in `range`, one byte apart.
+    ///
+    /// This is a stable stand-in for ranges of `ByteUnit`s, which require the
+    /// unstable [`Step`](core::iter::Step) trait. Unbounded ends are `0` and
+    /// [`ByteUnit::max_value()`], respectively. Iteration never overflows:
+    /// an inclusive range ending at `max_value()` yields it and stops. Use
+    /// [`Iterator::step_by()`] for steps larger than a byte.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteUnit, ToByteUnit};
+    ///
+    /// let mut range = ByteUnit::range(ByteUnit::KiB..ByteUnit::MiB);
+    /// assert_eq!(range.next(), Some(1.kibibytes()));
+    /// assert_eq!(range.next_back(), Some(1.mebibytes() - 1));
+    ///
+    /// let sizes: Vec<_> = ByteUnit::range(..=4.kibibytes()).step_by(1024).collect();
+    /// assert_eq!(sizes, [0, 1024, 2048, 3072, 4096]);
+    ///
+    /// let mut tail = ByteUnit::range(ByteUnit::max_value() - 1..);
+    /// assert_eq!(tail.next(), Some(ByteUnit::max_value() - 1));
+    /// assert_eq!(tail.next(), Some(ByteUnit::max_value()));
+    /// assert_eq!(tail.next(), None);
+    ///
+    /// assert_eq!(ByteUnit::range(5.bytes()..5.bytes()).count(), 0);
+    /// ```
+    pub fn range<R>(range: R) -> impl DoubleEndedIterator<Item = ByteUnit>
+        where R: core::ops::RangeBounds<ByteUnit>
+    {
+        use core::ops::Bound::*;
+
+        let start = match range.start_bound() {
+            Included(start) => Some(start.0),
+            Excluded(start) => start.0.checked_add(1),
+            Unbounded => Some(0),
+        };
+
+        let end = match range.end_bound() {
+            Included(end) => Some(end.0),
+            Excluded(end) => end.0.checked_sub(1),
+            Unbounded => Some(u64::MAX),
+        };
+
+        start.zip(end)
+            .map(|(start, end)| start..=end)
+            .into_iter()
+            .flatten()
+            .map(ByteUnit)
+    }
+
+    /// Returns `self` multiplied by the non-negative `factor`, truncating any
+    /// fractional byte and saturating at [`ByteUnit::max_value()`]. Negative
+    /// and NaN factors yield `0`, as does any factor when `self` is zero.
+    ///
+    /// The product is computed exactly from the value of `factor`, without
+    /// first converting `self` to an `f64`, so no precision is lost for large
+    /// values of `self`.
+    ///
+    /// `ByteUnit` doesn't implement [`Product`](core::iter::Product): the
+    /// product of two byte units is not a byte unit. Use `scale()` or integer
+    /// multiplication to scale a `ByteUnit` instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteUnit, ToByteUnit};
+    ///
+    /// assert_eq!(2.gigabytes().scale(1.5), 3.gigabytes());
+    /// assert_eq!(3.bytes().scale(0.5), 1.bytes());
+    /// assert_eq!(1.kibibytes().scale(0.25), 256.bytes());
+    /// assert_eq!(ByteUnit::max_value().scale(1.0), ByteUnit::max_value());
+    /// assert_eq!(ByteUnit::max_value().scale(0.5), ByteUnit::max_value() / 2);
+    ///
+    /// // Scaling saturates.
+    /// assert_eq!(10.exbibytes().scale(2.0), ByteUnit::max_value());
+    /// assert_eq!(10.bytes().scale(f64::INFINITY), ByteUnit::max_value());
+    /// assert_eq!(10.bytes().scale(-1.5), 0);
+    /// assert_eq!(10.bytes().scale(f64::NAN), 0);
+    /// assert_eq!(ByteUnit::ZERO.scale(1e300), 0);
+    /// assert_eq!(0.bytes().scale(f64::MAX), 0);
+    /// assert_eq!(ByteUnit::ZERO.scale(f64::INFINITY), 0);
+    /// ```
+    pub fn scale(self, factor: f64) -> ByteUnit {
+        if self.0 == 0 || factor.is_nan() || factor <= 0.0 {
+            return ByteUnit(0);
+        } else if factor.is_infinite() {
+            return ByteUnit::max_value();
+        }
+
+        // `factor` is exactly `mantissa * 2^exp`.
+        let bits = factor.to_bits();
+        let biased_exp = ((bits >> 52) & 0x7ff) as i32;
+        let (mantissa, exp) = match biased_exp {
+            0 => (bits & ((1 << 52) - 1), -1074),
+            _ => ((bits & ((1 << 52) - 1)) | (1 << 52), biased_exp - 1075),
+        };
+
+        let product = self.as_u128() * mantissa as u128;
+        let value = match exp {
+            _ if product == 0 => 0,
+            0.. if exp as u32 > product.leading_zeros() => u128::MAX,
+            0.. => product << exp,
+            -127..=-1 => product >> -exp,
+            _ => 0,
+        };
+
+        ByteUnit::from(value)
+    }
+
+    /// Returns an iterator over the non-zero IEC components of `self`, from
+    /// largest to smallest unit.
+    ///
+    /// Each component is a pair of a count and a `1`-valued IEC unit, one of
+    /// [`ByteUnit::EiB`], [`ByteUnit::PiB`], [`ByteUnit::TiB`],
+    /// [`ByteUnit::GiB`], [`ByteUnit::MiB`], [`ByteUnit::KiB`], or
+    /// [`ByteUnit::B`]. Components are peeled off greedily, so every count is
+    /// less than `1024` except possibly that of `EiB`, and the sum of `count *
+    /// unit` over all components is `self`. A zero `self` has no components.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteUnit, ToByteUnit};
+    ///
+    /// let value = 1.gibibytes() + 512.mebibytes() + 3.kibibytes();
+    /// let mut components = value.components();
+    /// assert_eq!(components.next(), Some((1, ByteUnit::GiB)));
+    /// assert_eq!(components.next(), Some((512, ByteUnit::MiB)));
+    /// assert_eq!(components.next(), Some((3, ByteUnit::KiB)));
+    /// assert_eq!(components.next(), None);
+    ///
+    /// let total: ByteUnit = value.components().map(|(n, unit)| n * unit).sum();
+    /// assert_eq!(total, value);
+    ///
+    /// assert_eq!(1.kilobytes().components().collect::<Vec<_>>(), [(1000, ByteUnit::B)]);
+    /// assert_eq!(0.bytes().components().count(), 0);
+    /// ```
+    pub fn components(self) -> impl Iterator<Item = (u64, ByteUnit)> {
+        const UNITS: &[ByteUnit] = &[
+            ByteUnit::EiB, ByteUnit::PiB, ByteUnit::TiB, ByteUnit::GiB,
+            ByteUnit::MiB, ByteUnit::KiB, ByteUnit::B,
+        ];
+
+        let mut rem = self.0;
+        UNITS.iter().filter_map(move |unit| {
+            let count = rem / unit.0;
+            rem %= unit.0;
+            Some((count, *unit)).filter(|_| count > 0)
+        })
+    }
+
+    /// Returns the components of the minimal unit representation of `self`.
+    ///
+    /// The "minimal unit representation" is the representation that maximizes
+    /// the SI-unit while minimizing the whole part of the value. For example,
+    /// `1024.bytes()` is minimally represented by `1KiB`, while `1023.bytes()`
+    /// is minimally represented by `1.023kB`.
+    ///
+    /// The four components returned, in tuple-order, are:
+    ///   * `whole` - the whole part of the minimal representation.
+    ///   * `frac` - the fractional part of the minimal representation.
+    ///   * `suffix` - the suffix of the minimal representation.
+    ///   * `unit` - the `1`-unit of the minimal representation.
+    ///
+    /// Succinctly, this is: `(whole, frac, suffix, unit)`. Observe that `(whole
+    /// + frac) * unit` reconstructs the original value, up to the precision of
+    /// an `f64`. Use [`ByteUnit::repr_exact()`] to reconstruct it exactly.
+    ///
+    /// With the `default-si` or `default-iec` feature enabled, this is instead
+    /// [`ByteUnit::repr_preferred()`] with [`UnitFamily::Si`] or
+    /// [`UnitFamily::Iec`], respectively, as is `Display`. If both are
+    /// enabled, `default-iec` takes precedence.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(not(any(feature = "default-si", feature = "default-iec")))] {
+    /// use ubyte::{ByteUnit, ToByteUnit};
+    ///
+    /// let value = 2.mebibytes() + 512.kibibytes();
+    /// assert_eq!(value.to_string(), "2.50MiB");
+    ///
+    /// let (whole, frac, suffix, unit) = value.repr();
+    /// assert_eq!(whole, 2);
+    /// assert_eq!(frac, 0.5);
+    /// assert_eq!(suffix, "MiB");
+    /// assert_eq!(unit, ByteUnit::MiB);
+    ///
+    /// let reconstructed = (whole as f64 + frac) * unit.as_u64() as f64;
+    /// assert_eq!(reconstructed as u64, value);
+    /// # }
+    /// ```
+    pub fn repr(self) -> (u64, f64, &'static str, ByteUnit) {
+        float_repr(self.int_repr())
+    }
+
+    /// Returns the components of the minimal unit representation of `self`
+    /// with the exact remainder, in bytes, in place of the fraction.
+    ///
+    /// This is exactly [`ByteUnit::repr()`] but never rounds: the components
+    /// returned are `(whole, rem, suffix, unit)`, where `rem` is less than
+    /// `unit` and `whole * unit + rem` is exactly `self`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(not(any(feature = "default-si", feature = "default-iec")))] {
+    /// use ubyte::{ByteUnit, ToByteUnit};
+    ///
+    /// let value = 2.mebibytes() + 512.kibibytes() + 1.bytes();
+    /// let (whole, rem, suffix, unit) = value.repr_exact();
+    /// assert_eq!(whole, 2);
+    /// assert_eq!(rem, 512 * 1024 + 1);
+    /// assert_eq!(suffix, "MiB");
+    /// assert_eq!(unit, ByteUnit::MiB);
+    /// assert_eq!(whole * unit + rem, value);
+    ///
+    /// let value = ByteUnit::max_value();
+    /// let (whole, rem, _, unit) = value.repr_exact();
+    /// assert_eq!(whole * unit.as_u64() + rem, u64::MAX);
+    /// # }
+    /// ```
+    pub fn repr_exact(self) -> (u64, u64, &'static str, ByteUnit) {
+        self.int_repr()
+    }
+
+    /// Returns [`ByteUnit::repr()`] with the remainder, in bytes, in place of
+    /// the fraction.
+    pub(crate) fn int_repr(self) -> IntRepr<ByteUnit> {
+        match DEFAULT_FAMILY {
+            UnitFamily::Auto => self.int_repr_auto(),
+            family => self.int_repr_preferred(family),
+        }
+    }
+
+    /// Returns [`ByteUnit::int_repr()`] as chosen by [`UnitFamily::Auto`]
+    /// regardless of the default family.
+    fn int_repr_auto(self) -> IntRepr<ByteUnit> {
+        rem_and_suffix! { ByteUnit, self.as_u64() =>
+            (EiB, EB), (TiB, TB), (GiB, GB), (MiB, MB), (KiB, kB) B
+        }
+    }
+
+    /// Returns the components of the minimal SI unit representation of
+    /// `self`. This is exactly [`ByteUnit::repr()`] but considers only the
+    /// decimal SI units: `kB`, `MB`, `GB`, `TB`, `PB`, and `EB`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(not(any(feature = "default-si", feature = "default-iec")))] {
+    /// use ubyte::{ByteUnit, ToByteUnit};
+    ///
+    /// let value = 2.mebibytes() + 512.kibibytes();
+    /// assert_eq!(value.repr().2, "MiB");
+    ///
+    /// let (whole, frac, suffix, unit) = value.repr_si();
+    /// assert_eq!((whole, suffix, unit), (2, "MB", ByteUnit::MB));
+    /// assert_eq!(frac, 0.62144);
+    /// # }
+    /// ```
+    pub fn repr_si(self) -> (u64, f64, &'static str, ByteUnit) {
+        float_repr(self.int_repr_si())
+    }
+
+    /// Returns [`ByteUnit::repr_si()`] with the remainder in place of the
+    /// fraction.
+    pub(crate) fn int_repr_si(self) -> IntRepr<ByteUnit> {
+        rem_and_suffix! { ByteUnit, self.as_u64() => EB, PB, TB, GB, MB, kB; B }
+    }
+
+    /// Returns the components of the minimal IEC unit representation of
+    /// `self`. This is exactly [`ByteUnit::repr()`] but considers only the
+    /// binary IEC units: `KiB`, `MiB`, `GiB`, `TiB`, `PiB`, and `EiB`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteUnit, ToByteUnit};
+    ///
+    /// let value = 3.megabytes();
+    /// assert_eq!(value.repr().2, "MB");
+    ///
+    /// let (whole, frac, suffix, unit) = value.repr_iec();
+    /// assert_eq!((whole, suffix, unit), (2, "MiB", ByteUnit::MiB));
+    /// assert!((frac - 0.861).abs() < 0.001);
+    /// ```
+    pub fn repr_iec(self) -> (u64, f64, &'static str, ByteUnit) {
+        float_repr(self.int_repr_iec())
+    }
+
+    /// Returns [`ByteUnit::repr_iec()`] with the remainder in place of the
+    /// fraction.
+    pub(crate) fn int_repr_iec(self) -> IntRepr<ByteUnit> {
+        rem_and_suffix! { ByteUnit, self.as_u64() => EiB, PiB, TiB, GiB, MiB, KiB; B }
+    }
+
+    /// Returns the components of the minimal unit representation of `self` in
+    /// the preferred unit `family`.
+    ///
+    /// With [`UnitFamily::Auto`], this is exactly [`ByteUnit::repr()`]. With
+    /// [`UnitFamily::Si`] or [`UnitFamily::Iec`], this is
+    /// [`ByteUnit::repr_si()`] or [`ByteUnit::repr_iec()`], respectively,
+    /// unless the value is an exact multiple of a unit in the other family and
+    /// either isn't an exact multiple of a unit in the preferred family or is
+    /// an exact multiple of a larger unit in the other family. Round numbers
+    /// thus display as round numbers.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteUnit, ToByteUnit, UnitFamily};
+    ///
+    /// // Prefer IEC units unless the value is an exact SI multiple.
+    /// let (whole, frac, suffix, _) = 3.mebibytes().repr_preferred(UnitFamily::Iec);
+    /// assert_eq!((whole, frac, suffix), (3, 0.0, "MiB"));
+    ///
+    /// let (whole, frac, suffix, _) = 3.megabytes().repr_preferred(UnitFamily::Iec);
+    /// assert_eq!((whole, frac, suffix), (3, 0.0, "MB"));
+    ///
+    /// let (whole, _, suffix, _) = 3_100.kilobytes().repr_preferred(UnitFamily::Iec);
+    /// assert_eq!((whole, suffix), (2, "MiB"));
+    ///
+    /// // Prefer SI units unless the value is an exact IEC multiple.
+    /// let (whole, frac, suffix, _) = 2.kibibytes().repr_preferred(UnitFamily::Si);
+    /// assert_eq!((whole, frac, suffix), (2, 0.0, "KiB"));
+    ///
+    /// let (whole, _, suffix, _) = 2_100.kibibytes().repr_preferred(UnitFamily::Si);
+    /// assert_eq!((whole, suffix), (2, "MB"));
+    ///
+    /// // `Auto` is `repr()`.
+    /// let value = 2.mebibytes() + 512.kibibytes();
+    /// assert_eq!(value.repr_preferred(UnitFamily::Auto), value.repr());
+    /// ```
+    pub fn repr_preferred(self, family: UnitFamily) -> (u64, f64, &'static str, ByteUnit) {
+        float_repr(self.int_repr_preferred(family))
+    }
+
+    /// Returns [`ByteUnit::repr_preferred()`] with the remainder in place of
+    /// the fraction.
+    pub(crate) fn int_repr_preferred(self, family: UnitFamily) -> IntRepr<ByteUnit> {
+        let (preferred, other) = match family {
+            UnitFamily::Auto => return self.int_repr(),
+            UnitFamily::Si => (self.int_repr_si(), self.int_repr_iec()),
+            UnitFamily::Iec => (self.int_repr_iec(), self.int_repr_si()),
+        };
+
+        match (preferred, other) {
+            ((_, p_rem, _, p_unit), (_, 0, _, o_unit)) if p_rem != 0 || o_unit > p_unit => other,
+            _ => preferred,
+        }
+    }
+
+    /// Returns the canonical suffix of the unit `self` if `self` is exactly a
+    /// `1`-valued unit, like [`ByteUnit::MiB`], and `None` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteUnit, ToByteUnit};
+    ///
+    /// assert_eq!(ByteUnit::MiB.suffix(), Some("MiB"));
+    /// assert_eq!(ByteUnit::kB.suffix(), Some("kB"));
+    /// assert_eq!(1.bytes().suffix(), Some("B"));
+    /// assert_eq!(1024.kibibytes().suffix(), Some("MiB"));
+    ///
+    /// assert_eq!(2.mebibytes().suffix(), None);
+    /// assert_eq!(ByteUnit::max_value().suffix(), None);
+    /// ```
+    pub const fn suffix(self) -> Option<&'static str> {
+        match self.find_unit() {
+            Some((_, suffix, _)) => Some(suffix),
+            None => None,
+        }
+    }
+
+    /// Returns the singular long name of the unit `self` if `self` is exactly
+    /// a `1`-valued unit, like [`ByteUnit::MiB`], and `None` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteUnit, ToByteUnit};
+    ///
+    /// assert_eq!(ByteUnit::MiB.unit_name(), Some("mebibyte"));
+    /// assert_eq!(ByteUnit::kB.unit_name(), Some("kilobyte"));
+    /// assert_eq!(1.bytes().unit_name(), Some("byte"));
+    ///
+    /// assert_eq!(3.kilobytes().unit_name(), None);
+    /// ```
+    pub const fn unit_name(self) -> Option<&'static str> {
+        match self.find_unit() {
+            Some((_, _, name)) => Some(name),
+            None => None,
+        }
+    }
+
+    /// Returns the entry in [`ByteUnit::units()`] for `self`, if any.
+    const fn find_unit(self) -> Option<(ByteUnit, &'static str, &'static str)> {
+        let mut i = 0;
+        while i < ByteUnit::UNITS.len() {
+            if ByteUnit::UNITS[i].0.0 == self.0 {
+                return Some(ByteUnit::UNITS[i]);
+            }
+
+            i += 1;
+        }
+
+        None
+    }
+
+    /// Returns every `1`-valued unit representable by a `ByteUnit`, from
+    /// [`ByteUnit::B`] to [`ByteUnit::EiB`] in increasing order, along with
+    /// its suffix and singular long name.
+    ///
+    /// Units larger than `EiB`, like `ZB`, exceed [`ByteUnit::max_value()`]
+    /// and are thus not included.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::ByteUnit;
+    ///
+    /// let units = ByteUnit::units();
+    /// assert_eq!(units.len(), 13);
+    /// assert_eq!(units[0], (ByteUnit::B, "B", "byte"));
+    /// assert_eq!(units[2], (ByteUnit::KiB, "KiB", "kibibyte"));
+    /// assert_eq!(units[12], (ByteUnit::EiB, "EiB", "exbibyte"));
+    ///
+    /// for &(unit, suffix, _) in units {
+    ///     assert_eq!(unit.suffix(), Some(suffix));
+    /// }
+    /// ```
+    pub const fn units() -> &'static [(ByteUnit, &'static str, &'static str)] {
+        ByteUnit::UNITS
+    }
+
+    /// Returns a value that displays `self` exactly as `Display` does except
+    /// that only the decimal SI units, like `kB` and `MB`, are used.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(not(any(feature = "default-si", feature = "default-iec")))] {
+    /// use ubyte::ToByteUnit;
+    ///
+    /// let value = 2.mebibytes() + 512.kibibytes();
+    /// assert_eq!(value.to_string(), "2.50MiB");
+    /// assert_eq!(value.si().to_string(), "2.62MB");
+    /// assert_eq!(format!("{:#.1}", value.si()), "2.6 MB");
+    /// assert_eq!(1.kibibytes().si().to_string(), "1.02kB");
+    /// # }
+    /// ```
+    pub fn si(self) -> UnitDisplay {
+        UnitDisplay::new(self, DisplayMode::Family(ByteUnit::int_repr_si))
+    }
+
+    /// Returns a value that displays `self` exactly as `Display` does except
+    /// that only the binary IEC units, like `KiB` and `MiB`, are used.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::ToByteUnit;
+    ///
+    /// let value = 3.megabytes();
+    /// assert_eq!(value.to_string(), "3MB");
+    /// assert_eq!(value.iec().to_string(), "2.86MiB");
+    /// assert_eq!(format!("{:#.1}", value.iec()), "2.9 MiB");
+    /// assert_eq!(1.kilobytes().iec().to_string(), "1000B");
+    /// ```
+    pub fn iec(self) -> UnitDisplay {
+        UnitDisplay::new(self, DisplayMode::Family(ByteUnit::int_repr_iec))
+    }
+
+    /// Returns a value that always displays `self` in the `1`-valued `unit`,
+    /// such as [`ByteUnit::MiB`], regardless of the magnitude of `self`. If
+    /// `unit` isn't one of the `1`-valued unit constants from `B` to `EiB`,
+    /// `self` is displayed in bytes.
+    ///
+    /// The value is always displayed with the formatter's precision, `2` by
+    /// default, so that values in a column align. As with `Display`, the width
+    /// applies to the whole part and the alternate flag, `#`, inserts a space
+    /// before the unit.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteUnit, ToByteUnit};
+    ///
+    /// assert_eq!(10.kibibytes().display_in(ByteUnit::MiB).to_string(), "0.01MiB");
+    /// assert_eq!(4.gibibytes().display_in(ByteUnit::MiB).to_string(), "4096.00MiB");
+    /// assert_eq!(format!("{:.1}", 1536.kibibytes().display_in(ByteUnit::MiB)), "1.5MiB");
+    /// assert_eq!(format!("{:#.0}", 1537.kibibytes().display_in(ByteUnit::MiB)), "2 MiB");
+    /// assert_eq!(format!("{:05.3}", 1.kilobytes().display_in(ByteUnit::KiB)), "00000.977KiB");
+    /// assert_eq!(1.kilobytes().display_in(ByteUnit::B).to_string(), "1000.00B");
+    /// ```
+    pub fn display_in(self, unit: ByteUnit) -> UnitDisplay {
+        UnitDisplay::new(self, DisplayMode::Fixed(unit))
+    }
+
+    /// Returns a value that displays `self` exactly as `Display` does except
+    /// that the fractional part is rounded to the formatter's precision with
+    /// `rounding`. Use [`UnitDisplay::rounded()`] to combine a rounding mode
+    /// with [`ByteUnit::si()`], [`ByteUnit::iec()`], or
+    /// [`ByteUnit::display_in()`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(not(any(feature = "default-si", feature = "default-iec")))] {
+    /// use ubyte::{Rounding, ToByteUnit};
+    ///
+    /// let value = 7.gibibytes() + 920.mebibytes();
+    /// assert_eq!(format!("{:.0}", value), "8GiB");
+    /// assert_eq!(format!("{:.0}", value.rounded(Rounding::Floor)), "7GiB");
+    /// assert_eq!(format!("{:.1}", value.rounded(Rounding::Ceil)), "7.9GiB");
+    /// assert_eq!(format!("{:.2}", value.rounded(Rounding::Floor)), "7.89GiB");
+    ///
+    /// let value = 1.megabytes() + 1.bytes();
+    /// assert_eq!(value.to_string(), "1.00MB");
+    /// assert_eq!(value.rounded(Rounding::Ceil).to_string(), "1.01MB");
+    /// assert_eq!(format!("{:.0}", value.rounded(Rounding::Ceil)), "2MB");
+    /// # }
+    /// ```
+    pub fn rounded(self, rounding: Rounding) -> UnitDisplay {
+        UnitDisplay::new(self, DisplayMode::Family(ByteUnit::int_repr)).rounded(rounding)
+    }
+
+    /// Returns a value that displays `self` exactly as `Display` does except
+    /// with `n` significant digits in place of a fixed number of fractional
+    /// digits. See [`UnitDisplay::significant()`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(not(any(feature = "default-si", feature = "default-iec")))] {
+    /// use ubyte::ToByteUnit;
+    ///
+    /// let value = 7.gigabytes() + 58.mebibytes() + 3.kilobytes();
+    /// assert_eq!(value.to_string(), "7.06GB");
+    /// assert_eq!(value.significant(1).to_string(), "7GB");
+    /// assert_eq!(value.significant(2).to_string(), "7.1GB");
+    /// assert_eq!(value.significant(4).to_string(), "7.061GB");
+    ///
+    /// assert_eq!(3.megabytes().significant(3).to_string(), "3MB");
+    /// assert_eq!((523.mebibytes() + 400.kibibytes()).significant(2).to_string(), "523MiB");
+    /// # }
+    /// ```
+    pub fn significant(self, n: usize) -> UnitDisplay {
+        UnitDisplay::new(self, DisplayMode::Family(ByteUnit::int_repr)).significant(n)
+    }
+
+    /// Returns a value that displays `self` exactly as `Display` does except
+    /// that trailing zeros, and then a trailing `.`, are stripped from the
+    /// fractional part. The formatter's precision, `2` by default, is thus the
+    /// maximum number of fractional digits. Use [`UnitDisplay::trimmed()`] to
+    /// trim the output of [`ByteUnit::si()`], [`ByteUnit::iec()`], or
+    /// [`ByteUnit::display_in()`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(not(any(feature = "default-si", feature = "default-iec")))] {
+    /// use ubyte::ToByteUnit;
+    ///
+    /// let value = 7.gibibytes() + 920.mebibytes();
+    /// assert_eq!(format!("{:.3}", value), "7.898GiB");
+    /// assert_eq!(format!("{:.1}", value), "7.9GiB");
+    /// assert_eq!(format!("{:.2}", value), "7.90GiB");
+    /// assert_eq!(format!("{:.2}", value.trimmed()), "7.9GiB");
+    /// assert_eq!(format!("{:.0}", value.trimmed()), "8GiB");
+    ///
+    /// assert_eq!((2.mebibytes() + 512.kibibytes()).trimmed().to_string(), "2.5MiB");
+    /// assert_eq!(format!("{:#.3}", (1.megabytes() + 1.bytes()).trimmed()), "1 MB");
+    /// assert_eq!(3.megabytes().trimmed().to_string(), "3MB");
+    /// # }
+    /// ```
+    pub fn trimmed(self) -> UnitDisplay {
+        UnitDisplay::new(self, DisplayMode::Family(ByteUnit::int_repr)).trimmed()
+    }
+
+    /// Returns a [`UnitDisplay`] builder that displays `self` exactly as
+    /// `Display` does until configured otherwise. All presentation options --
+    /// the unit or family of units, rounding, trimming, spacing, and precision
+    /// -- are set on the returned value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(not(any(feature = "default-si", feature = "default-iec")))] {
+    /// use ubyte::{ByteUnit, Rounding, ToByteUnit, UnitFamily};
+    ///
+    /// let value = 7.gibibytes() + 920.mebibytes();
+    /// assert_eq!(value.format().to_string(), value.to_string());
+    /// assert_eq!(value.format().space(true).precision(1).to_string(), "7.9 GiB");
+    /// assert_eq!(value.format().family(UnitFamily::Si).to_string(), "8.48GB");
+    /// assert_eq!(value.format().unit(ByteUnit::MiB).precision(0).to_string(), "8088MiB");
+    ///
+    /// let display = value.format()
+    ///     .family(UnitFamily::Iec)
+    ///     .rounded(Rounding::Floor)
+    ///     .precision(3)
+    ///     .trimmed()
+    ///     .space(true);
+    ///
+    /// assert_eq!(display.to_string(), "7.898 GiB");
+    /// # }
+    /// ```
+    pub fn format(self) -> UnitDisplay {
+        UnitDisplay::new(self, DisplayMode::Family(ByteUnit::int_repr))
+    }
+
+    /// Returns a value that displays `self` exactly as `Display` does except
+    /// that the unit is written out as its English name, preceded by a space:
+    /// `7.06 gigabytes`. The name is singular only when the displayed value is
+    /// exactly `1`. The names are those accepted by `FromStr`, so verbose
+    /// output parses back into the displayed value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(not(any(feature = "default-si", feature = "default-iec")))] {
+    /// use ubyte::{ByteUnit, ToByteUnit};
+    ///
+    /// assert_eq!(1.megabytes().verbose().to_string(), "1 megabyte");
+    /// assert_eq!(3.mebibytes().verbose().to_string(), "3 mebibytes");
+    /// assert_eq!(1.bytes().verbose().to_string(), "1 byte");
+    /// assert_eq!(0.bytes().verbose().to_string(), "0 bytes");
+    ///
+    /// let value = 7.gigabytes() + 58.mebibytes() + 3.kilobytes();
+    /// assert_eq!(value.verbose().to_string(), "7.06 gigabytes");
+    /// assert_eq!(format!("{:.0}", value.verbose()), "7 gigabytes");
+    ///
+    /// let parsed: ByteUnit = value.verbose().to_string().parse().unwrap();
+    /// assert_eq!(parsed, 7060.megabytes());
+    /// # }
+    /// ```
+    pub fn verbose(self) -> UnitDisplay {
+        self.format().long_names(true)
+    }
+
+    /// Returns a value that displays `self` in bits using the decimal SI bit
+    /// units: `bit`, `kbit`, `Mbit`, `Gbit`, `Tbit`, `Pbit`, and `Ebit`.
+    ///
+    /// As with [`BitUnit`](crate::BitUnit)'s `Display`, a space separates the
+    /// value and the suffix by default. Unlike [`ByteUnit::to_bits()`], the
+    /// number of bits never saturates. Precision, width, rounding, trimming,
+    /// and separators apply as they do to bytes; setting a unit or family of
+    /// byte units displays bytes instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::ToByteUnit;
+    ///
+    /// assert_eq!(1.megabytes().display_bits().to_string(), "8 Mbit");
+    /// assert_eq!(1.bytes().display_bits().to_string(), "8 bit");
+    /// assert_eq!(1.kibibytes().display_bits().to_string(), "8.19 kbit");
+    /// assert_eq!(format!("{:.0}", 1.kibibytes().display_bits()), "8 kbit");
+    /// assert_eq!(format!("{:.3}", 1.kibibytes().display_bits().space(false)), "8.192kbit");
+    /// assert_eq!(16.exbibytes().display_bits().to_string(), "147.57 Ebit");
+    /// ```
+    pub fn display_bits(self) -> UnitDisplay {
+        UnitDisplay::new(self, DisplayMode::Bits).space(true)
+    }
+}
+
+impl From<ByteUnit> for u64 {
+    #[inline(always)]
+    fn from(v: ByteUnit) -> Self {
+        v.as_u64()
+    }
+}
+
+impl From<ByteUnit> for u128 {
+    #[inline(always)]
+    fn from(v: ByteUnit) -> Self {
+        v.as_u128()
+    }
+}
+
+/// Converts as [`ByteUnit::as_f64()`] does: values larger than `2^53` are
+/// rounded to the nearest representable `f64`.
+impl From<ByteUnit> for f64 {
+    #[inline(always)]
+    fn from(v: ByteUnit) -> Self {
+        v.as_f64()
+    }
+}
+
+/// Converts as [`ByteUnit::as_f32()`] does: values larger than `2^24`, or
+/// `16MiB`, are rounded to the nearest representable `f32`.
+impl From<ByteUnit> for f32 {
+    #[inline(always)]
+    fn from(v: ByteUnit) -> Self {
+        v.as_f32()
+    }
+}
+
+macro_rules! impl_try_from_byte_unit {
+    ($($T:ty),*) => ($(
+        /// Converts the value of bytes represented by a `ByteUnit` into a
+        #[doc = concat!("`", stringify!($T), "`, failing if the value doesn't fit.")]
+        impl core::convert::TryFrom<ByteUnit> for $T {
+            type Error = core::num::TryFromIntError;
+
+            #[inline(always)]
+            fn try_from(v: ByteUnit) -> Result<Self, Self::Error> {
+                <$T>::try_from(v.as_u64())
+            }
+        }
+    )*)
+}
+
+impl_try_from_byte_unit!(u8, u16, u32, usize);
+
+macro_rules! impl_from_int_unknown {
+    ($U:ident, $T:ty) => (
+        impl From<$T> for $U {
+            #[inline(always)]
+            fn from(value: $T) -> Self {
+                if core::mem::size_of::<$T>() <= core::mem::size_of::<i64>() {
+                    $U::from(value as i64)
+                } else if value <= 0 {
+                    $U(0)
+                } else if value as u128 <= u64::MAX as u128 {
+                    $U(value as u64)
+                } else {
+                    $U::max_value()
+                }
+            }
+        }
+    )
+}
+
+macro_rules! impl_from_uint_unknown {
+    ($U:ident, $T:ty) => (
+        impl From<$T> for $U {
+            #[inline(always)]
+            fn from(value: $T) -> Self {
+                if core::mem::size_of::<$T>() <= core::mem::size_of::<u64>() {
+                    $U(value as u64)
+                } else if value <= u64::MAX as $T {
+                    $U(value as u64)
+                } else {
+                    $U::max_value()
+                }
+            }
+        }
+    )
+}
+
+macro_rules! impl_from_unsigned {
+    ($U:ident, $T:ty) => (
+        impl From<$T> for $U {
+            #[inline(always)] fn from(v: $T) -> Self { $U(v as u64) }
+        }
+    )
+}
+
+macro_rules! impl_from_signed {
+    ($U:ident, $T:ty) => (
+        impl From<$T> for $U {
+            #[inline(always)] fn from(v: $T) -> Self {
+                $U(core::cmp::max(v, 0) as u64)
+            }
+        }
+    )
+}
+
+macro_rules! impl_from_ints {
+    ($U:ident) => (
+        impl_from_unsigned!($U, u8);
+        impl_from_unsigned!($U, u16);
+        impl_from_unsigned!($U, u32);
+        impl_from_unsigned!($U, u64);
+
+        impl_from_signed!($U, i8);
+        impl_from_signed!($U, i16);
+        impl_from_signed!($U, i32);
+        impl_from_signed!($U, i64);
+
+        impl_from_uint_unknown!($U, usize);
+        impl_from_uint_unknown!($U, u128);
+        impl_from_int_unknown!($U, isize);
+        impl_from_int_unknown!($U, i128);
+    )
+}
+
+impl_from_ints!(ByteUnit);
+
+/// Error returned by [`ByteUnit::try_from_f64()`], [`ByteUnit::try_from_f32()`],
+/// and the corresponding `TryFrom` implementations when a float isn't a valid
+/// number of bytes.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum FromFloatError {
+    /// The float was NaN.
+    Nan,
+    /// The float was negative.
+    Negative,
+    /// The float was positive infinity.
+    Infinite,
+    /// The float exceeds [`ByteUnit::max_value()`].
+    Overflow,
+}
+
+impl core::fmt::Display for FromFloatError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            FromFloatError::Nan => write!(f, "byte units cannot be NaN"),
+            FromFloatError::Negative => write!(f, "byte units cannot be negative"),
+            FromFloatError::Infinite => write!(f, "byte units cannot be infinite"),
+            FromFloatError::Overflow => write!(f,
+                "byte unit exceeds the maximum of `{}`", u64::MAX),
+        }
+    }
+}
+
+impl core::error::Error for FromFloatError {}
+
+impl ByteUnit {
+    /// Converts the number of bytes `v` into a `ByteUnit`, rounding any
+    /// fractional byte as directed by `rounding`. Negative values and NaN
+    /// convert to `0` while values beyond [`ByteUnit::max_value()`] saturate.
+    ///
+    /// [`FloatToByteUnit::bytes()`] is exactly `from_f64(v, Rounding::Floor)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteUnit, Rounding};
+    ///
+    /// assert_eq!(ByteUnit::from_f64(1.7, Rounding::Floor), 1);
+    /// assert_eq!(ByteUnit::from_f64(1.2, Rounding::Ceil), 2);
+    /// assert_eq!(ByteUnit::from_f64(1.5, Rounding::Nearest), 2);
+    /// assert_eq!(ByteUnit::from_f64(1.49, Rounding::Nearest), 1);
+    /// assert_eq!(ByteUnit::from_f64(1.5, Rounding::Standard), 1);
+    /// assert_eq!(ByteUnit::from_f64(3.0, Rounding::Ceil), 3);
+    ///
+    /// assert_eq!(ByteUnit::from_f64(-0.7, Rounding::Ceil), 0);
+    /// assert_eq!(ByteUnit::from_f64(f64::NAN, Rounding::Nearest), 0);
+    /// assert_eq!(ByteUnit::from_f64(1e20, Rounding::Floor), ByteUnit::max_value());
+    /// assert_eq!(ByteUnit::from_f64(f64::INFINITY, Rounding::Ceil), ByteUnit::max_value());
+    /// ```
+    pub fn from_f64(v: f64, rounding: Rounding) -> ByteUnit {
+        // Casting truncates toward zero and saturates, mapping NaN to `0`. Any
+        // value that isn't a whole number is below `2^53`, so `v - whole` is
+        // exact.
+        let whole = v as u64;
+        let round_up = match rounding {
+            Rounding::Floor => false,
+            Rounding::Ceil => v > whole as f64,
+            Rounding::Nearest => v - whole as f64 >= 0.5,
+            Rounding::Standard => v - whole as f64 > 0.5,
+        };
+
+        ByteUnit(whole.saturating_add(round_up as u64))
+    }
+
+    /// Strictly converts the number of bytes `v` into a `ByteUnit`, truncating
+    /// any fractional byte.
+    ///
+    /// Unlike the saturating [`ByteUnit::from_f64()`], NaN, negative,
+    /// infinite, and too large values are rejected with a [`FromFloatError`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteUnit, FromFloatError, ToByteUnit};
+    ///
+    /// assert_eq!(ByteUnit::try_from_f64(3.5e9), Ok(3500.megabytes()));
+    /// assert_eq!(ByteUnit::try_from_f64(1.5), Ok(1.bytes()));
+    /// assert_eq!(ByteUnit::try_from_f64(-0.0), Ok(0.bytes()));
+    ///
+    /// assert_eq!(ByteUnit::try_from_f64(f64::NAN), Err(FromFloatError::Nan));
+    /// assert_eq!(ByteUnit::try_from_f64(-1.0), Err(FromFloatError::Negative));
+    /// assert_eq!(ByteUnit::try_from_f64(f64::INFINITY), Err(FromFloatError::Infinite));
+    /// assert_eq!(ByteUnit::try_from_f64(1e20), Err(FromFloatError::Overflow));
+    ///
+    /// // This is also available via `TryFrom`.
+    /// use core::convert::TryFrom;
+    ///
+    /// assert_eq!(ByteUnit::try_from(3.5e9_f64), Ok(3500.megabytes()));
+    /// assert_eq!(ByteUnit::try_from(-1.0_f32), Err(FromFloatError::Negative));
+    /// ```
+    pub fn try_from_f64(v: f64) -> Result<ByteUnit, FromFloatError> {
+        if v.is_nan() {
+            Err(FromFloatError::Nan)
+        } else if v < 0.0 {
+            Err(FromFloatError::Negative)
+        } else if v.is_infinite() {
+            Err(FromFloatError::Infinite)
+        } else if v >= u64::MAX as f64 {
+            Err(FromFloatError::Overflow)
+        } else {
+            Ok(ByteUnit(v as u64))
+        }
+    }
+
+    /// Strictly converts the number of bytes `v` into a `ByteUnit`. This is
+    /// exactly [`ByteUnit::try_from_f64()`] for an `f32`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteUnit, FromFloatError, ToByteUnit};
+    ///
+    /// assert_eq!(ByteUnit::try_from_f32(2.5e6), Ok(2500.kilobytes()));
+    /// assert_eq!(ByteUnit::try_from_f32(-1.0), Err(FromFloatError::Negative));
+    /// ```
+    pub fn try_from_f32(v: f32) -> Result<ByteUnit, FromFloatError> {
+        ByteUnit::try_from_f64(v as f64)
+    }
+}
+
+macro_rules! impl_try_from_float {
+    ($($T:ty => $f:ident),*) => ($(
+        /// Strictly converts the number of bytes in a
+        #[doc = concat!("`", stringify!($T), "` into a `ByteUnit` as")]
+        #[doc = concat!("[`ByteUnit::", stringify!($f), "()`] does.")]
+        impl core::convert::TryFrom<$T> for ByteUnit {
+            type Error = FromFloatError;
+
+            #[inline(always)]
+            fn try_from(v: $T) -> Result<Self, Self::Error> {
+                ByteUnit::$f(v)
+            }
+        }
+    )*)
+}
+
+impl_try_from_float!(f64 => try_from_f64, f32 => try_from_f32);
+
+macro_rules! helper_fn {
+    ($T:ident, $base:ident, $kindstr:expr, $name:ident = $kind:ident) => (
+        #[doc = concat!("Converts `self` to a `", stringify!($T), "` representing `self`")]
+        #[doc = $kindstr]
+        /// .
+        #[inline(always)]
+        fn $name(self) -> $T {
+            self.$base() * $T::$kind
+        }
+    );
+
+    ($T:ident, $base:ident => $name:ident = $kind:ident) => (
+        helper_fn!($T, $base, stringify!($kind), $name = $kind);
+    );
+
+    ($name:ident = $kind:ident) => (
+        helper_fn!(ByteUnit, bytes => $name = $kind);
+    );
+
+    (float $name:ident = $kind:ident) => (
+        #[doc = concat!("Converts `self` to a `ByteUnit` representing `self` ",
+            stringify!($kind), ", truncating any fractional byte.")]
+        #[inline(always)]
+        fn $name(self) -> ByteUnit {
+            ByteUnit::$kind.scale(self.into())
+        }
+    )
+}
+
+/// Extension trait for conversion from integer types to [`ByteUnit`].
+///
+/// The `ToByteUnit` trait provides methods on integer types that convert the
+/// integer type into the [`ByteUnit`] unit represented by the method name. To
+/// use the trait, simply import it. The trait is implemented for all integer
+/// types. For floats, use [`FloatToByteUnit`].
+///
+/// As with all other `ByteUnit` operations, conversions saturate.
+///
+/// # Example
+///
+/// ```rust
+/// use ubyte::ToByteUnit;
+///
+/// assert_eq!(512.kilobytes(), 512000.bytes());
+/// assert_eq!(512.kibibytes(), 524288.bytes());
+/// assert_eq!(512.kilobytes(), 512 * 1.kilobytes());
+///
+/// assert_eq!(1000.bytes(), 1.kilobytes());
+/// assert_eq!(1000.bytes() + 24, 1.kibibytes());
+/// assert_eq!(2048.mebibytes(), 2.gibibytes());
+///
+/// assert!(2.megabytes() + 500.kilobytes() > 2.mebibytes());
+/// assert!(2.pebibytes() > 2.petabytes());
+///
+/// // As with other `ByteUnit` operations, conversions saturate.
+/// assert_eq!((1 << 10).exbibytes(), (1 << 20).exbibytes());
+/// ```
+pub trait ToByteUnit: Into<ByteUnit> {
+    /// Converts `self` to a `ByteUnit` representing `self` bytes.
+    #[inline(always)]
+    fn bytes(self) -> ByteUnit {
+        self.into()
+    }
+
+    /// Converts `self`, a number of bits, to the `ByteUnit` needed to hold
+    /// it: `self / 8`, rounded up. `10.bits_ceil()` is `2.bytes()`.
+    ///
+    /// Converting bits to bytes is lossy unless `self` is a multiple of `8`.
+    /// Use [`ToByteUnit::bits_floor()`] to round down instead. To keep a
+    /// count of bits exactly, use [`ToBitUnit::bits()`](crate::ToBitUnit::bits).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::ToByteUnit;
+    ///
+    /// assert_eq!(10.bits_ceil(), 2.bytes());
+    /// assert_eq!(8.bits_ceil(), 1.bytes());
+    /// assert_eq!(1.bits_ceil(), 1.bytes());
+    /// assert_eq!(0.bits_ceil(), 0.bytes());
+    /// ```
+    #[inline(always)]
+    fn bits_ceil(self) -> ByteUnit {
+        ByteUnit::from_bits_ceil(self.bytes().as_u64())
+    }
+
+    /// Converts `self`, a number of bits, to the `ByteUnit` of whole bytes it
+    /// contains: `self / 8`, rounded down. `10.bits_floor()` is `1.bytes()`.
+    ///
+    /// Converting bits to bytes is lossy unless `self` is a multiple of `8`.
+    /// Use [`ToByteUnit::bits_ceil()`] to round up instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::ToByteUnit;
+    ///
+    /// assert_eq!(10.bits_floor(), 1.bytes());
+    /// assert_eq!(8.bits_floor(), 1.bytes());
+    /// assert_eq!(7.bits_floor(), 0.bytes());
+    /// ```
+    #[inline(always)]
+    fn bits_floor(self) -> ByteUnit {
+        ByteUnit::from_bits(self.bytes().as_u64())
+    }
+
+    helper_fn!(kilobytes = kB);
     helper_fn!(kibibytes = KiB);
     helper_fn!(megabytes = MB);
     helper_fn!(mebibytes = MiB);
@@ -405,53 +1783,1344 @@ pub trait ToByteUnit: Into<ByteUnit> {
     helper_fn!(exbibytes = EiB);
 }
 
-impl<T: Into<ByteUnit> + Copy> ToByteUnit for T {}
+impl<T: Into<ByteUnit> + Copy> ToByteUnit for T {}
+
+/// Extension trait for conversion from float types to [`ByteUnit`].
+///
+/// The `FloatToByteUnit` trait provides the methods of [`ToByteUnit`] on `f32`
+/// and `f64`. The unit is scaled by the float before truncating any
+/// fractional byte, so `2.5.megabytes()` is `2_500_000` bytes. Negative values
+/// and NaN convert to `0`.
+///
+/// Floats don't convert into a `ByteUnit` with `From`: arithmetic operations
+/// convert their operand into a `ByteUnit`, which would silently truncate the
+/// float. To scale a `ByteUnit` by a float, use [`ByteUnit::scale()`].
+///
+/// As with all other `ByteUnit` operations, conversions saturate.
+///
+/// # Example
+///
+/// ```rust
+/// use ubyte::{FloatToByteUnit, ToByteUnit};
+///
+/// assert_eq!(2.5.megabytes(), 2_500_000.bytes());
+/// assert_eq!(0.5f32.kibibytes(), 512.bytes());
+/// assert_eq!(1.9.bytes(), 1.bytes());
+///
+/// // As with other `ByteUnit` operations, conversions saturate.
+/// assert_eq!(1e30.bytes(), (1 << 20).exbibytes());
+/// assert_eq!((-2.5).megabytes(), 0);
+/// assert_eq!(f64::NAN.megabytes(), 0);
+/// ```
+///
+/// Arithmetic with a float operand fails to compile:
+///
+/// ```rust,compile_fail
+/// use ubyte::ToByteUnit;
+///
+/// let value = 2.kilobytes() * 1.5;
+/// ```
+pub trait FloatToByteUnit: Into<f64> + Copy {
+    /// Converts `self` to a `ByteUnit` representing `self` bytes, truncating
+    /// any fractional byte. This is exactly [`ByteUnit::from_f64()`] with
+    /// [`Rounding::Floor`].
+    #[inline(always)]
+    fn bytes(self) -> ByteUnit {
+        ByteUnit::from_f64(self.into(), Rounding::Floor)
+    }
+
+    helper_fn!(float kilobytes = kB);
+    helper_fn!(float kibibytes = KiB);
+    helper_fn!(float megabytes = MB);
+    helper_fn!(float mebibytes = MiB);
+    helper_fn!(float gigabytes = GB);
+    helper_fn!(float gibibytes = GiB);
+    helper_fn!(float terabytes = TB);
+    helper_fn!(float tibibytes = TiB);
+    helper_fn!(float petabytes = PB);
+    helper_fn!(float pebibytes = PiB);
+    helper_fn!(float exabytes = EB);
+    helper_fn!(float exbibytes = EiB);
+}
+
+impl FloatToByteUnit for f32 {}
+impl FloatToByteUnit for f64 {}
+
+/// Display `self` as best as possible. For perfectly custom display output,
+/// consider using [`ByteUnit::repr()`]. With the alternate flag, `{:#}`, a
+/// space is inserted between the value and the unit suffix.
+///
+/// The fractional part is rounded to the nearest value at the formatter's
+/// precision, `2` by default, as described by [`Rounding::Standard`]: ties
+/// round to even, except that with a precision of `0`, ties round down. When
+/// rounding carries into the whole part, the rounded-up value is
+/// displayed instead, promoting the unit as needed: `999.999kB` is `1MB`, not
+/// `999.100kB` or `1000kB`. The fraction is computed exactly with integer
+/// arithmetic: displaying a `ByteUnit` never uses floating point.
+///
+/// To display the raw number of bytes in `self` instead, use `{:x}`, `{:X}`,
+/// `{:o}`, or `{:b}`, which format exactly as the equivalent `u64` would:
+/// `format!("{:#x}", 1.mebibytes())` is `"0x100000"`.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(not(any(feature = "default-si", feature = "default-iec")))] {
+/// use ubyte::{ByteUnit, ToByteUnit};
+///
+/// assert_eq!(323.kilobytes().to_string(), "323kB");
+/// assert_eq!(3.megabytes().to_string(), "3MB");
+/// assert_eq!(3.mebibytes().to_string(), "3MiB");
+///
+/// assert_eq!((3.mebibytes() + 140.kilobytes()).to_string(), "3.13MiB");
+/// assert_eq!((3.mebibytes() + 2.mebibytes()).to_string(), "5MiB");
+/// assert_eq!((7.gigabytes() + 58.mebibytes() + 3.kilobytes()).to_string(), "7.06GB");
+/// assert_eq!((7.gibibytes() + 920.mebibytes()).to_string(), "7.90GiB");
+/// assert_eq!(7231.kilobytes().to_string(), "6.90MiB");
+///
+/// assert_eq!(format!("{:.0}", 7.gibibytes() + 920.mebibytes()), "8GiB");
+/// assert_eq!(format!("{:.1}", 7.gibibytes() + 920.mebibytes()), "7.9GiB");
+/// assert_eq!(format!("{:.2}", 7.gibibytes() + 920.mebibytes()), "7.90GiB");
+/// assert_eq!(format!("{:.3}", 7.gibibytes() + 920.mebibytes()), "7.898GiB");
+/// assert_eq!(format!("{:.4}", 7.gibibytes() + 920.mebibytes()), "7.8984GiB");
+/// assert_eq!(format!("{:.4}", 7231.kilobytes()), "6.8960MiB");
+/// assert_eq!(format!("{:.0}", 7231.kilobytes()), "7MiB");
+/// assert_eq!(format!("{:.2}", 999.kilobytes() + 990.bytes()), "976.55KiB");
+/// assert_eq!(format!("{:.0}", 999.kilobytes() + 990.bytes()), "1MB");
+///
+/// assert_eq!(format!("{:04.2}", 999.kilobytes() + 990.bytes()), "0976.55KiB");
+/// assert_eq!(format!("{:02.0}", 999.kilobytes() + 990.bytes()), "01MB");
+/// assert_eq!(format!("{:04.0}", 999.kilobytes() + 990.bytes()), "0001MB");
+///
+/// // Rounding that carries into the whole part promotes the unit.
+/// assert_eq!((1.mebibytes() - 1.bytes()).iec().to_string(), "1MiB");
+/// assert_eq!(format!("{:.0}", (999.kilobytes() + 600.bytes()).si()), "1MB");
+///
+/// // The alternate flag, `#`, inserts a space between the value and unit.
+/// assert_eq!(format!("{:#}", 323.kilobytes()), "323 kB");
+/// assert_eq!(format!("{:#}", 7.gigabytes() + 58.mebibytes() + 3.kilobytes()), "7.06 GB");
+/// assert_eq!(format!("{:#.0}", 7.gibibytes() + 920.mebibytes()), "8 GiB");
+/// assert_eq!(format!("{:#04.2}", 999.kilobytes() + 990.bytes()), "0976.55 KiB");
+/// # }
+/// ```
+impl core::fmt::Display for ByteUnit {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        UnitDisplay::new(*self, DisplayMode::Family(ByteUnit::int_repr)).fmt(f)
+    }
+}
+
+/// Displays the exact number of bytes followed by the human-friendly `Display`
+/// form in a comment so that, for instance, failed assertions are readable.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(not(any(feature = "default-si", feature = "default-iec")))] {
+/// use ubyte::ToByteUnit;
+///
+/// assert_eq!(format!("{:?}", 512.kibibytes()), "ByteUnit(524288 /* 512KiB */)");
+/// assert_eq!(format!("{:?}", 1536.bytes()), "ByteUnit(1536 /* 1.50KiB */)");
+/// assert_eq!(format!("{:?}", Some(0.bytes())), "Some(ByteUnit(0 /* 0B */))");
+/// # }
+/// ```
+impl core::fmt::Debug for ByteUnit {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "ByteUnit({} /* {} */)", self.0, self)
+    }
+}
+
+/// Returns the formatter's precision, `2` by default, clamped to `19`, the
+/// most decimal digits a `u64` fraction can hold.
+pub(crate) fn precision(f: &core::fmt::Formatter<'_>) -> usize {
+    f.precision().unwrap_or(2).min(19)
+}
+
+/// Rounds the fraction `rem / unit` to `p` decimal digits with `rounding`,
+/// returning the digits as an integer or `None` if rounding carries into the
+/// whole part.
+pub(crate) fn round_frac(rem: u64, unit: u64, p: usize, rounding: Rounding) -> Option<u64> {
+    let k = 10u64.saturating_pow(p as u32);
+    let scaled = rem as u128 * k as u128;
+    let (q, r) = (scaled / unit as u128, scaled % unit as u128);
+    let frac = match rounding {
+        Rounding::Floor => q,
+        Rounding::Ceil => q + (r != 0) as u128,
+        Rounding::Nearest => q + (2 * r >= unit as u128) as u128,
+        Rounding::Standard => match (2 * r).cmp(&(unit as u128)) {
+            core::cmp::Ordering::Less => q,
+            core::cmp::Ordering::Equal if p == 0 => q,
+            core::cmp::Ordering::Equal => q + q % 2,
+            core::cmp::Ordering::Greater => q + 1,
+        },
+    };
+
+    if frac < k as u128 { Some(frac as u64) } else { None }
+}
+
+/// Returns `(whole + 1) * unit`, the value a carry out of the fraction rounds
+/// up to, or `None` if it isn't representable as a `u64`.
+pub(crate) fn promote(whole: u64, unit: u64) -> Option<u64> {
+    whole.checked_add(1)?.checked_mul(unit)
+}
+
+/// How a [`UnitDisplay`] chooses its unit.
+#[derive(Copy, Clone)]
+enum DisplayMode {
+    /// The minimal unit from the representation returned by the function.
+    Family(fn(ByteUnit) -> IntRepr<ByteUnit>),
+    /// Always the given unit.
+    Fixed(ByteUnit),
+    /// The minimal SI bit unit.
+    Bits,
+}
+
+/// The family of units preferred by [`ByteUnit::repr()`] and thus `Display`:
+/// `Iec` with the `default-iec` feature enabled, `Si` with only `default-si`
+/// enabled, and `Auto` otherwise.
+///
+/// Features are unified across a build, so two crates may enable both. IEC
+/// wins in that case as it is the exact, power-of-two family.
+const DEFAULT_FAMILY: UnitFamily = if cfg!(feature = "default-iec") {
+    UnitFamily::Iec
+} else if cfg!(feature = "default-si") {
+    UnitFamily::Si
+} else {
+    UnitFamily::Auto
+};
+
+/// A family of units to prefer when representing a [`ByteUnit`].
+///
+/// Used with [`ByteUnit::repr_preferred()`].
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum UnitFamily {
+    /// Choose between SI and IEC units as [`ByteUnit::repr()`] does. Unless
+    /// the `default-si` or `default-iec` feature is enabled, the unit that
+    /// best fits the value is chosen from either family. If both features are
+    /// enabled, `default-iec` takes precedence.
+    #[default]
+    Auto,
+    /// Prefer the decimal SI units: `kB`, `MB`, `GB`, `TB`, `PB`, and `EB`.
+    Si,
+    /// Prefer the binary IEC units: `KiB`, `MiB`, `GiB`, `TiB`, `PiB`, and
+    /// `EiB`.
+    Iec,
+}
+
+/// How the fractional part of a displayed value is rounded to the formatter's
+/// precision.
+///
+/// Used with [`ByteUnit::rounded()`] and [`UnitDisplay::rounded()`]. In all
+/// modes, a fraction that rounds up to the next whole is carried into the
+/// whole part, promoting the unit as needed.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(not(any(feature = "default-si", feature = "default-iec")))] {
+/// use ubyte::{Rounding, ToByteUnit};
+///
+/// let value = 2.mebibytes() + 512.kibibytes();
+/// assert_eq!(format!("{:.0}", value.rounded(Rounding::Floor)), "2MiB");
+/// assert_eq!(format!("{:.0}", value.rounded(Rounding::Ceil)), "3MiB");
+/// assert_eq!(format!("{:.0}", value.rounded(Rounding::Nearest)), "3MiB");
+/// assert_eq!(format!("{:.0}", value.rounded(Rounding::Standard)), "2MiB");
+/// assert_eq!(format!("{:.0}", value), "2MiB");
+/// # }
+/// ```
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Round toward zero, truncating the fraction.
+    Floor,
+    /// Round up to the next representable value unless the value is exact.
+    Ceil,
+    /// Round to the nearest representable value, with ties rounding up.
+    Nearest,
+    /// Round to the nearest representable value. Ties round to even, except
+    /// when rounding to a whole number, where ties round down: `1.125KiB` is
+    /// `1.12KiB` at a precision of `2` while `1.5KiB` is `1KiB` at a
+    /// precision of `0`. This is the rounding used by `ByteUnit`'s `Display`
+    /// implementation.
+    #[default]
+    Standard,
+}
+
+/// A `ByteUnit` displayed with a restricted family of units, a fixed unit, a
+/// rounding mode, or without trailing zeros.
+///
+/// Returned by [`ByteUnit::format()`], [`ByteUnit::si()`], [`ByteUnit::iec()`],
+/// [`ByteUnit::display_in()`], [`ByteUnit::display_bits()`],
+/// [`ByteUnit::rounded()`], [`ByteUnit::significant()`], and
+/// [`ByteUnit::trimmed()`], and configured with its builder methods. The
+/// `Display` implementation honors the same flags as `ByteUnit`'s. The
+/// precision and spacing may also be fixed with [`UnitDisplay::precision()`]
+/// and [`UnitDisplay::space()`], which take priority over the formatter's.
+/// With [`UnitDisplay::pad_whole()`] disabled, the width pads the entire
+/// output rather than only the whole part.
+#[derive(Copy, Clone)]
+pub struct UnitDisplay {
+    value: ByteUnit,
+    mode: DisplayMode,
+    rounding: Rounding,
+    trim: bool,
+    space: Option<bool>,
+    precision: Option<usize>,
+    significant: Option<usize>,
+    long_names: bool,
+    decimal_point: char,
+    grouping: Option<char>,
+    pad_whole: bool,
+}
+
+impl UnitDisplay {
+    const fn new(value: ByteUnit, mode: DisplayMode) -> UnitDisplay {
+        UnitDisplay {
+            value,
+            mode,
+            rounding: Rounding::Standard,
+            trim: false,
+            space: None,
+            precision: None,
+            significant: None,
+            long_names: false,
+            decimal_point: '.',
+            grouping: None,
+            pad_whole: true,
+        }
+    }
+
+    /// Always displays the value in the `1`-valued `unit`, as
+    /// [`ByteUnit::display_in()`] does.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteUnit, ToByteUnit};
+    ///
+    /// let value = 1536.kibibytes();
+    /// assert_eq!(value.format().unit(ByteUnit::MiB).to_string(), "1.50MiB");
+    /// assert_eq!(value.format().unit(ByteUnit::kB).to_string(), "1572.86kB");
+    /// ```
+    pub fn unit(mut self, unit: ByteUnit) -> UnitDisplay {
+        self.mode = DisplayMode::Fixed(unit);
+        self
+    }
+
+    /// Displays the value in the minimal unit of `family`: as `Display` does
+    /// for [`UnitFamily::Auto`], as [`ByteUnit::si()`] does for
+    /// [`UnitFamily::Si`], and as [`ByteUnit::iec()`] does for
+    /// [`UnitFamily::Iec`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ToByteUnit, UnitFamily};
+    ///
+    /// let value = 3.megabytes();
+    /// assert_eq!(value.format().family(UnitFamily::Auto).to_string(), "3MB");
+    /// assert_eq!(value.format().family(UnitFamily::Iec).to_string(), "2.86MiB");
+    /// assert_eq!(1.kibibytes().format().family(UnitFamily::Si).to_string(), "1.02kB");
+    /// ```
+    pub fn family(mut self, family: UnitFamily) -> UnitDisplay {
+        self.mode = DisplayMode::Family(match family {
+            UnitFamily::Auto => ByteUnit::int_repr,
+            UnitFamily::Si => ByteUnit::int_repr_si,
+            UnitFamily::Iec => ByteUnit::int_repr_iec,
+        });
+
+        self
+    }
+
+    /// Sets whether a space separates the value and the unit suffix,
+    /// overriding the formatter's alternate flag, `#`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::ToByteUnit;
+    ///
+    /// assert_eq!(3.mebibytes().format().space(true).to_string(), "3 MiB");
+    /// assert_eq!(format!("{:#}", 3.mebibytes().format().space(false)), "3MiB");
+    /// ```
+    pub fn space(mut self, space: bool) -> UnitDisplay {
+        self.space = Some(space);
+        self
+    }
+
+    /// Sets the number of fractional digits, overriding the formatter's
+    /// precision. As with the formatter's precision, values above `19` are
+    /// treated as `19`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(not(any(feature = "default-si", feature = "default-iec")))] {
+    /// use ubyte::ToByteUnit;
+    ///
+    /// let value = 7.gibibytes() + 920.mebibytes();
+    /// assert_eq!(value.format().precision(3).to_string(), "7.898GiB");
+    /// assert_eq!(format!("{:.3}", value.format().precision(0)), "8GiB");
+    /// # }
+    /// ```
+    pub fn precision(mut self, precision: usize) -> UnitDisplay {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// Sets the number of significant digits in the displayed value,
+    /// overriding both the formatter's precision and
+    /// [`UnitDisplay::precision()`].
+    ///
+    /// The number of fractional digits is chosen after the unit so that the
+    /// whole and fractional digits together number `n`. The whole part is
+    /// never rounded, so it may have more than `n` digits on its own, and
+    /// exact values and values that round up to a whole number have no
+    /// fractional digits. An `n` of `0` is treated as `1`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(not(any(feature = "default-si", feature = "default-iec")))] {
+    /// use ubyte::{ByteUnit, ToByteUnit};
+    ///
+    /// let value = 7.gibibytes() + 920.mebibytes();
+    /// assert_eq!(value.format().significant(1).to_string(), "8GiB");
+    /// assert_eq!(value.format().significant(3).to_string(), "7.90GiB");
+    /// assert_eq!(value.format().significant(3).trimmed().to_string(), "7.9GiB");
+    /// assert_eq!(format!("{:.5}", value.format().significant(2)), "7.9GiB");
+    ///
+    /// let value = 9.gibibytes() + 980.mebibytes();
+    /// assert_eq!(value.format().significant(2).to_string(), "10GiB");
+    /// assert_eq!(value.format().unit(ByteUnit::MiB).significant(2).to_string(), "10196MiB");
+    /// # }
+    /// ```
+    pub fn significant(mut self, n: usize) -> UnitDisplay {
+        self.significant = Some(n);
+        self
+    }
+
+    /// Sets whether the unit is displayed by its English name, such as
+    /// `megabytes`, in place of its suffix, as [`ByteUnit::verbose()`] does.
+    /// A name is always preceded by a space and is singular only when the
+    /// displayed value is exactly `1`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(not(any(feature = "default-si", feature = "default-iec")))] {
+    /// use ubyte::{ByteUnit, ToByteUnit};
+    ///
+    /// let value = 1536.kibibytes();
+    /// assert_eq!(value.format().long_names(true).to_string(), "1.50 mebibytes");
+    /// assert_eq!(value.format().long_names(true).trimmed().to_string(), "1.5 mebibytes");
+    /// assert_eq!(value.format().unit(ByteUnit::GiB).long_names(true).to_string(),
+    ///     "0.00 gibibytes");
+    /// # }
+    /// ```
+    pub fn long_names(mut self, long_names: bool) -> UnitDisplay {
+        self.long_names = long_names;
+        self
+    }
+
+    /// Sets the character that separates the whole and fractional parts of
+    /// the value. The default is `.`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(not(any(feature = "default-si", feature = "default-iec")))] {
+    /// use ubyte::ToByteUnit;
+    ///
+    /// let value = 7.gigabytes() + 58.mebibytes() + 3.kilobytes();
+    /// assert_eq!(value.format().decimal_point(',').space(true).to_string(), "7,06 GB");
+    /// # }
+    /// ```
+    pub fn decimal_point(mut self, decimal_point: char) -> UnitDisplay {
+        self.decimal_point = decimal_point;
+        self
+    }
+
+    /// Sets the character that separates groups of three digits in the whole
+    /// part of the value. By default, digits aren't grouped. Zeros added to
+    /// pad the value to the formatter's width are grouped as well.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteUnit, ToByteUnit};
+    ///
+    /// let value = 1234.kibibytes();
+    /// let display = value.format().unit(ByteUnit::KiB).precision(0);
+    /// assert_eq!(display.to_string(), "1234KiB");
+    /// assert_eq!(display.grouping('.').to_string(), "1.234KiB");
+    /// assert_eq!(display.grouping(',').decimal_point('.').to_string(), "1,234KiB");
+    ///
+    /// let value = 1_234_567.bytes();
+    /// let display = value.format().unit(ByteUnit::B).grouping('.').decimal_point(',');
+    /// assert_eq!(format!("{:#}", display), "1.234.567,00 B");
+    /// assert_eq!(format!("{:010.0}", display), "0.001.234.567B");
+    /// ```
+    pub fn grouping(mut self, grouping: char) -> UnitDisplay {
+        self.grouping = Some(grouping);
+        self
+    }
+
+    /// Sets the [`Rounding`] used to round the fractional part of the value
+    /// to the formatter's precision. The default is [`Rounding::Standard`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteUnit, Rounding, ToByteUnit};
+    ///
+    /// let value = 1.megabytes() + 1.bytes();
+    /// assert_eq!(value.si().to_string(), "1.00MB");
+    /// assert_eq!(value.si().rounded(Rounding::Ceil).to_string(), "1.01MB");
+    ///
+    /// let value = 1536.kibibytes();
+    /// let display = value.display_in(ByteUnit::MiB).rounded(Rounding::Floor);
+    /// assert_eq!(format!("{:.0}", display), "1MiB");
+    /// ```
+    pub fn rounded(mut self, rounding: Rounding) -> UnitDisplay {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Strips trailing zeros, and then a trailing `.`, from the fractional
+    /// part of the value after it is rounded to the formatter's precision.
+    /// The precision thus becomes the maximum number of fractional digits.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteUnit, ToByteUnit};
+    ///
+    /// let value = 1536.kibibytes();
+    /// assert_eq!(value.display_in(ByteUnit::MiB).to_string(), "1.50MiB");
+    /// assert_eq!(value.display_in(ByteUnit::MiB).trimmed().to_string(), "1.5MiB");
+    /// assert_eq!(value.display_in(ByteUnit::KiB).trimmed().to_string(), "1536KiB");
+    /// ```
+    pub fn trimmed(mut self) -> UnitDisplay {
+        self.trim = true;
+        self
+    }
+
+    /// Sets whether the formatter's width zero-pads only the whole part of
+    /// the value, as in `0976.55KiB`, or pads the entire output, as in
+    /// `  976.55KiB`. Enabled, and thus padding only the whole part, by
+    /// default.
+    ///
+    /// When disabled, the entire output is padded to the width with the
+    /// formatter's fill character, a space by default, and aligned as
+    /// requested, to the right by default. The `0` flag is then ignored.
+    /// Outputs with differing units thus line up in columns.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(not(any(feature = "default-si", feature = "default-iec")))] {
+    /// use ubyte::ToByteUnit;
+    ///
+    /// let value = 999.kilobytes() + 990.bytes();
+    /// assert_eq!(format!("{:04.2}", value), "0976.55KiB");
+    /// assert_eq!(format!("{:04.2}", value.format()), "0976.55KiB");
+    ///
+    /// let display = value.format().pad_whole(false);
+    /// assert_eq!(format!("{:11.2}", display), "  976.55KiB");
+    /// assert_eq!(format!("{:<11.2}", display), "976.55KiB  ");
+    /// assert_eq!(format!("{:*^13.1}", display), "**976.6KiB***");
+    /// assert_eq!(format!("{:#12.0}", display), "        1 MB");
+    /// assert_eq!(format!("{:012.0}", display), "         1MB");
+    /// assert_eq!(format!("{:4}", display), "976.55KiB");
+    /// # }
+    /// ```
+    pub fn pad_whole(mut self, pad_whole: bool) -> UnitDisplay {
+        self.pad_whole = pad_whole;
+        self
+    }
+
+    /// Returns the number of fractional digits to display after the whole
+    /// part `whole`.
+    fn digits(&self, f: &core::fmt::Formatter<'_>, whole: u128) -> usize {
+        match (self.significant, self.precision) {
+            (Some(n), _) => {
+                let whole_digits = whole.checked_ilog10().map_or(1, |d| d as usize + 1);
+                n.max(1).saturating_sub(whole_digits).min(19)
+            }
+            (None, Some(p)) => p.min(19),
+            (None, None) => precision(f),
+        }
+    }
+
+    /// Displays `value` using the unit representation returned by `repr`.
+    fn fmt_repr(
+        &self,
+        value: ByteUnit,
+        repr: fn(ByteUnit) -> IntRepr<ByteUnit>,
+        f: &mut core::fmt::Formatter<'_>,
+    ) -> core::fmt::Result {
+        let (whole, rem, suffix, unit) = repr(value);
+        let p = self.digits(f, whole.into());
+        match round_frac(rem, unit.0, p, self.rounding) {
+            Some(frac) if rem != 0 => self.write(f, whole.into(), frac, p, suffix),
+            Some(_) => self.write(f, whole.into(), 0, 0, suffix),
+            None => match promote(whole, unit.as_u64()) {
+                Some(next) => self.fmt_repr(ByteUnit(next), repr, f),
+                None => self.write(f, whole as u128 + 1, 0, 0, suffix),
+            }
+        }
+    }
+
+    /// Displays `value` in the `1`-valued `unit`, always with the formatter's
+    /// precision.
+    fn fmt_fixed(
+        &self,
+        value: ByteUnit,
+        unit: ByteUnit,
+        f: &mut core::fmt::Formatter<'_>,
+    ) -> core::fmt::Result {
+        let (unit, suffix) = match unit.suffix() {
+            Some(suffix) => (unit, suffix),
+            None => (ByteUnit::B, "B"),
+        };
+
+        let (whole, rem) = (value.0 / unit.0, value.0 % unit.0);
+        let p = self.digits(f, whole.into());
+        match round_frac(rem, unit.0, p, self.rounding) {
+            Some(frac) => self.write(f, whole.into(), frac, p, suffix),
+            None => self.write(f, whole as u128 + 1, 0, p, suffix),
+        }
+    }
+
+    /// Displays `bits` in the minimal SI bit unit.
+    fn fmt_bits(&self, bits: u128, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use crate::BitUnit;
+
+        const UNITS: &[(BitUnit, &str)] = &[
+            (BitUnit::Ebit, "Ebit"), (BitUnit::Pbit, "Pbit"), (BitUnit::Tbit, "Tbit"),
+            (BitUnit::Gbit, "Gbit"), (BitUnit::Mbit, "Mbit"), (BitUnit::kbit, "kbit"),
+        ];
+
+        let (unit, suffix) = UNITS.iter()
+            .map(|&(unit, suffix)| (unit.as_u64(), suffix))
+            .find(|&(unit, _)| bits >= unit as u128)
+            .unwrap_or((1, "bit"));
+
+        let (whole, rem) = (bits / unit as u128, (bits % unit as u128) as u64);
+        let p = self.digits(f, whole);
+        match round_frac(rem, unit, p, self.rounding) {
+            Some(frac) if rem != 0 => self.write(f, whole, frac, p, suffix),
+            Some(_) => self.write(f, whole, 0, 0, suffix),
+            None => self.fmt_bits((whole + 1) * unit as u128, f),
+        }
+    }
+
+    /// Writes `whole`, the `p` fractional digits `frac`, if any, and `suffix`,
+    /// honoring the formatter's width and the configured or formatter's
+    /// spacing.
+    fn write(
+        &self,
+        f: &mut core::fmt::Formatter<'_>,
+        whole: u128,
+        mut frac: u64,
+        mut p: usize,
+        suffix: &str,
+    ) -> core::fmt::Result {
+        while self.trim && p > 0 && frac % 10 == 0 {
+            frac /= 10;
+            p -= 1;
+        }
+
+        let mut space = if self.space.unwrap_or(f.alternate()) { " " } else { "" };
+        let (suffix, plural) = match crate::parse::unit_name(suffix) {
+            Some(name) if self.long_names => {
+                space = " ";
+                (name, if whole == 1 && frac == 0 { "" } else { "s" })
+            }
+            _ => (suffix, ""),
+        };
+
+        if self.pad_whole {
+            self.write_number(f, whole, frac, p, f.width().unwrap_or(0))?;
+            return write!(f, "{}{}{}", space, suffix, plural);
+        }
+
+        let mut len = CharCount(space.len() + suffix.len() + plural.len());
+        self.write_number(&mut len, whole, frac, p, 0)?;
+        write_padded(f, len.0, |f| {
+            self.write_number(f, whole, frac, p, 0)?;
+            write!(f, "{}{}{}", space, suffix, plural)
+        })
+    }
+
+    /// Writes `whole` zero-padded to `width` digits followed by the `p`
+    /// fractional digits `frac`, if any.
+    fn write_number<W: core::fmt::Write>(
+        &self,
+        out: &mut W,
+        whole: u128,
+        frac: u64,
+        p: usize,
+        width: usize,
+    ) -> core::fmt::Result {
+        self.write_whole(out, whole, width)?;
+        if p > 0 {
+            out.write_char(self.decimal_point)?;
+            write!(out, "{:0p$}", frac, p = p)?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes `whole` zero-padded to `width` digits, grouping digits if
+    /// configured to.
+    fn write_whole<W: core::fmt::Write>(
+        &self,
+        f: &mut W,
+        whole: u128,
+        width: usize,
+    ) -> core::fmt::Result {
+        let grouping = match self.grouping {
+            Some(grouping) => grouping,
+            None => return write!(f, "{:0width$}", whole, width = width),
+        };
+
+        let digits = whole.checked_ilog10().unwrap_or(0) as usize + 1;
+        let n = digits.max(width);
+        let mut sep = [0; 4];
+        let sep = grouping.encode_utf8(&mut sep);
+        for i in 0..n {
+            if i > 0 && (n - i) % 3 == 0 {
+                f.write_str(sep)?;
+            }
+
+            let digit = match n - 1 - i {
+                k if k >= digits => 0,
+                k => (whole / 10u128.pow(k as u32)) % 10,
+            };
+
+            write!(f, "{}", digit)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A `core::fmt::Write` that counts the characters written to it.
+pub(crate) struct CharCount(pub usize);
 
-/// Display `self` as best as possible. For perfectly custom display output,
-/// consider using [`ByteUnit::repr()`].
-///
-/// # Example
-///
-/// ```rust
-/// use ubyte::{ByteUnit, ToByteUnit};
-///
-/// assert_eq!(323.kilobytes().to_string(), "323kB");
-/// assert_eq!(3.megabytes().to_string(), "3MB");
-/// assert_eq!(3.mebibytes().to_string(), "3MiB");
-///
-/// assert_eq!((3.mebibytes() + 140.kilobytes()).to_string(), "3.13MiB");
-/// assert_eq!((3.mebibytes() + 2.mebibytes()).to_string(), "5MiB");
-/// assert_eq!((7.gigabytes() + 58.mebibytes() + 3.kilobytes()).to_string(), "7.06GB");
-/// assert_eq!((7.gibibytes() + 920.mebibytes()).to_string(), "7.90GiB");
-/// assert_eq!(7231.kilobytes().to_string(), "6.90MiB");
-///
-/// assert_eq!(format!("{:.0}", 7.gibibytes() + 920.mebibytes()), "8GiB");
-/// assert_eq!(format!("{:.1}", 7.gibibytes() + 920.mebibytes()), "7.9GiB");
-/// assert_eq!(format!("{:.2}", 7.gibibytes() + 920.mebibytes()), "7.90GiB");
-/// assert_eq!(format!("{:.3}", 7.gibibytes() + 920.mebibytes()), "7.898GiB");
-/// assert_eq!(format!("{:.4}", 7.gibibytes() + 920.mebibytes()), "7.8984GiB");
-/// assert_eq!(format!("{:.4}", 7231.kilobytes()), "6.8960MiB");
-/// assert_eq!(format!("{:.0}", 7231.kilobytes()), "7MiB");
-/// assert_eq!(format!("{:.2}", 999.kilobytes() + 990.bytes()), "976.55KiB");
-/// assert_eq!(format!("{:.0}", 999.kilobytes() + 990.bytes()), "1MB");
-///
-/// assert_eq!(format!("{:04.2}", 999.kilobytes() + 990.bytes()), "0976.55KiB");
-/// assert_eq!(format!("{:02.0}", 999.kilobytes() + 990.bytes()), "01MB");
-/// assert_eq!(format!("{:04.0}", 999.kilobytes() + 990.bytes()), "0001MB");
-/// ```
-impl core::fmt::Display for ByteUnit {
+impl core::fmt::Write for CharCount {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+/// Writes `len` characters with `write`, padded to the width of `f` with its
+/// fill and alignment, right-aligned by default.
+pub(crate) fn write_padded<W>(
+    f: &mut core::fmt::Formatter<'_>,
+    len: usize,
+    write: W,
+) -> core::fmt::Result
+    where W: FnOnce(&mut core::fmt::Formatter<'_>) -> core::fmt::Result
+{
+    use core::fmt::Write;
+
+    let pad = f.width().unwrap_or(0).saturating_sub(len);
+    let (before, after) = match f.align() {
+        Some(core::fmt::Alignment::Left) => (0, pad),
+        Some(core::fmt::Alignment::Center) => (pad / 2, pad - pad / 2),
+        _ => (pad, 0),
+    };
+
+    let fill = f.fill();
+    (0..before).try_for_each(|_| f.write_char(fill))?;
+    write(f)?;
+    (0..after).try_for_each(|_| f.write_char(fill))
+}
+
+impl core::fmt::Display for UnitDisplay {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let (whole, rem, suffix, unit) = self.repr();
-        let width = f.width().unwrap_or(0);
-        if rem != 0f64 && f.precision().map(|p| p > 0).unwrap_or(true) {
-            let p = f.precision().unwrap_or(2);
-            let k = 10u64.saturating_pow(p as u32) as f64;
-            write!(f, "{:0width$}.{:0p$.0}{}", whole, rem * k, suffix,
-                p = p, width = width)
-        } else if rem > 0.5f64 {
-            ((whole.bytes() + 1) * unit).fmt(f)
-        } else {
-            write!(f, "{:0width$}{}", whole, suffix, width = width)
+        match self.mode {
+            DisplayMode::Family(repr) => self.fmt_repr(self.value, repr, f),
+            DisplayMode::Fixed(unit) => self.fmt_fixed(self.value, unit, f),
+            DisplayMode::Bits => self.fmt_bits(self.value.as_u128() * 8, f),
+        }
+    }
+}
+
+impl core::fmt::Debug for UnitDisplay {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("UnitDisplay").field(&self.value).finish()
+    }
+}
+
+macro_rules! impl_radix_fmt {
+    ($($Trait:ident),*) => ($(
+        /// Formats the raw number of bytes in `self` exactly as the equivalent
+        /// `u64` would be formatted, honoring all flags.
+        impl core::fmt::$Trait for ByteUnit {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::$Trait::fmt(&self.0, f)
+            }
+        }
+    )*)
+}
+
+impl_radix_fmt!(LowerHex, UpperHex, Octal, Binary);
+
+#[cfg(test)]
+mod byte_unit_tests {
+    use crate::{ByteUnit, ToByteUnit, BitUnit, ToBitUnit};
+
+    #[test]
+    fn test_bits_to_bytes_rounding() {
+        const CEIL: ByteUnit = ByteUnit::from_bits_ceil(9);
+        assert_eq!(CEIL, 2);
+
+        for bits in 0u64..64 {
+            assert_eq!(bits.bits_floor(), bits / 8);
+            assert_eq!(bits.bits_ceil(), bits / 8 + (bits % 8 != 0) as u64);
+            assert_eq!(bits.bits_floor(), bits.bits().to_bytes());
+        }
+
+        assert_eq!(u64::MAX.bits_ceil(), u64::MAX / 8 + 1);
+        assert_eq!(u64::MAX.bits_floor(), u64::MAX / 8);
+        assert_eq!(u128::MAX.bits_ceil(), u64::MAX / 8 + 1);
+        assert_eq!((-8i32).bits_ceil(), 0);
+    }
+
+    #[test]
+    fn test_from_int_boundaries() {
+        let max = ByteUnit::max_value();
+        let max64 = u64::MAX;
+
+        assert_eq!(ByteUnit::from(max64 as u128 - 1), max64 - 1);
+        assert_eq!(ByteUnit::from(max64 as u128), max);
+        assert_eq!(ByteUnit::from(max64 as u128 + 1), max);
+        assert_eq!(ByteUnit::from(u128::MAX), max);
+        assert_eq!(ByteUnit::from(0u128), 0);
+
+        assert_eq!(ByteUnit::from(i64::MAX as i128), i64::MAX as u64);
+        assert_eq!(ByteUnit::from(i64::MAX as i128 + 1), i64::MAX as u64 + 1);
+        assert_eq!(ByteUnit::from(max64 as i128 - 1), max64 - 1);
+        assert_eq!(ByteUnit::from(max64 as i128), max);
+        assert_eq!(ByteUnit::from(max64 as i128 + 1), max);
+        assert_eq!(ByteUnit::from(i128::MAX), max);
+        assert_eq!(ByteUnit::from(0i128), 0);
+        assert_eq!(ByteUnit::from(-1i128), 0);
+        assert_eq!(ByteUnit::from(i128::MIN), 0);
+
+        assert_eq!(ByteUnit::from(usize::MAX), usize::MAX as u64);
+        assert_eq!(ByteUnit::from(isize::MAX), isize::MAX as u64);
+        assert_eq!(ByteUnit::from(isize::MIN), 0);
+        assert_eq!(ByteUnit::from(i64::MIN), 0);
+
+        assert_eq!(BitUnit::from(max64 as i128 - 1), max64 - 1);
+        assert_eq!(BitUnit::from(max64 as u128 + 1), BitUnit::max_value());
+
+        for v in [0, 1, 1 << 32, i64::MAX as u64, i64::MAX as u64 + 1, max64] {
+            assert_eq!(u64::from(ByteUnit::from(v as u128)), v);
+            assert_eq!(u64::from(ByteUnit::from(v as i128)), v);
+            assert_eq!(u128::from(ByteUnit::from(v as u128)), v as u128);
+        }
+    }
+
+    #[test]
+    fn test_into_float() {
+        fn total<T: Into<f64> + Copy>(values: &[T]) -> f64 {
+            values.iter().map(|&v| v.into()).sum()
+        }
+
+        let sizes = [1.kibibytes(), 512.bytes(), 0.bytes()];
+        assert_eq!(total(&sizes), 1536.0);
+        assert_eq!(sizes.iter().map(|s| f64::from(*s)).sum::<f64>(), 1536.0);
+        assert_eq!(f64::from(ByteUnit::max_value()), u64::MAX as f64);
+        assert_eq!(f32::from(16.mebibytes()), 16_777_216.0);
+        assert_eq!(f32::from(16.mebibytes() + 1), 16_777_216.0);
+        assert_eq!(f32::from(ByteUnit::max_value()), u64::MAX as f32);
+        let x: f64 = 3.kilobytes().into();
+        assert_eq!(x, 3000.0);
+    }
+
+    #[test]
+    fn test_try_from() {
+        use core::convert::TryFrom;
+
+        assert_eq!(u8::try_from(255.bytes()), Ok(255));
+        assert!(u8::try_from(256.bytes()).is_err());
+        assert_eq!(u16::try_from(64.kibibytes() - 1), Ok(u16::MAX));
+        assert!(u16::try_from(64.kibibytes()).is_err());
+        assert_eq!(u32::try_from(4.gibibytes() - 1), Ok(u32::MAX));
+        assert!(u32::try_from(4.gibibytes()).is_err());
+        assert_eq!(usize::try_from(1.mebibytes()), Ok(1 << 20));
+        assert_eq!(usize::try_from(ByteUnit::max_value()).is_ok(), usize::BITS >= 64);
+
+        use crate::FromFloatError;
+        assert_eq!(ByteUnit::try_from(3.5e9_f64), Ok(3500.megabytes()));
+        assert_eq!(ByteUnit::try_from(1.9_f64), Ok(1.bytes()));
+        assert_eq!(ByteUnit::try_from(2.5e6_f32), Ok(2500.kilobytes()));
+        assert_eq!(ByteUnit::try_from(f64::NAN), Err(FromFloatError::Nan));
+        assert_eq!(ByteUnit::try_from(-1.0_f32), Err(FromFloatError::Negative));
+        assert_eq!(ByteUnit::try_from(f32::INFINITY), Err(FromFloatError::Infinite));
+        assert_eq!(ByteUnit::try_from(1e20_f64), Err(FromFloatError::Overflow));
+    }
+
+    #[test]
+    fn test_min_max_clamp() {
+        assert_eq!(1.kibibytes().min(1.kilobytes()), 1.kilobytes());
+        assert_eq!(1.kibibytes().max(1.kilobytes()), 1.kibibytes());
+        assert_eq!(0.bytes().clamp(10.bytes(), 20.bytes()), 10);
+        assert_eq!(15.bytes().clamp(10.bytes(), 20.bytes()), 15);
+        assert_eq!(25.bytes().clamp(10.bytes(), 20.bytes()), 20);
+        assert_eq!(25.bytes().clamp(20.bytes(), 20.bytes()), 20);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn test_clamp_bad_interval() {
+        let _ = 15.bytes().clamp(20.bytes(), 10.bytes());
+    }
+
+    #[test]
+    fn test_radix_fmt() {
+        extern crate std;
+        use std::format;
+
+        assert_eq!(format!("{:#x}", 1.mebibytes()), "0x100000");
+        assert_eq!(format!("{:x}", 255.bytes()), "ff");
+        assert_eq!(format!("{:X}", 255.bytes()), "FF");
+        assert_eq!(format!("{:#X}", 255.bytes()), "0xFF");
+        assert_eq!(format!("{:o}", 8.bytes()), "10");
+        assert_eq!(format!("{:#o}", 8.bytes()), "0o10");
+        assert_eq!(format!("{:b}", 5.bytes()), "101");
+        assert_eq!(format!("{:#010b}", 5.bytes()), "0b00000101");
+        assert_eq!(format!("{:>8x}", 4.kibibytes()), "    1000");
+        assert_eq!(format!("{:*<6x}", 4.kibibytes()), "1000**");
+    }
+
+    #[test]
+    #[cfg_attr(any(feature = "default-si", feature = "default-iec"), ignore = "expects Auto")]
+    fn test_display_rounding_ties() {
+        extern crate std;
+        use std::format;
+        use crate::{FloatToByteUnit, Rounding};
+
+        assert_eq!(format!("{:.0}", 2.5.mebibytes()), "2MiB");
+        assert_eq!(format!("{:.0}", 3.5.mebibytes()), "3MiB");
+        assert_eq!(format!("{:.0}", 1536.bytes()), "1KiB");
+        assert_eq!(format!("{:.1}", 1536.bytes()), "1.5KiB");
+        assert_eq!(format!("{:.2}", 1152.bytes()), "1.12KiB");
+        assert_eq!(format!("{:.2}", 1.375.kibibytes()), "1.38KiB");
+        assert_eq!(format!("{:.2}", 1015.bytes()), "1.02kB");
+        assert_eq!(format!("{:.2}", 1005.bytes()), "1.00kB");
+        assert_eq!(format!("{:.0}", 2.5.kibibytes().rounded(Rounding::Nearest)), "3KiB");
+        assert_eq!(format!("{:.2}", 1152.bytes().rounded(Rounding::Nearest)), "1.13KiB");
+        assert_eq!(format!("{:.2}", 1152.bytes().rounded(Rounding::Standard)), "1.12KiB");
+    }
+
+    #[test]
+    #[cfg_attr(any(feature = "default-si", feature = "default-iec"), ignore = "expects Auto")]
+    fn test_display_rounding_carry() {
+        extern crate std;
+        use std::format;
+
+        let kb_999_5 = 999.kilobytes() + 500.bytes();
+        assert_eq!(format!("{:.0}", kb_999_5.si()), "999kB");
+        assert_eq!(format!("{:.0}", (kb_999_5 + 1).si()), "1MB");
+        assert_eq!(format!("{:.0}", kb_999_5.si().rounded(crate::Rounding::Nearest)), "1MB");
+        assert_eq!(format!("{:.1}", kb_999_5.si()), "999.5kB");
+        assert_eq!(format!("{:.0}", (999.kilobytes() + 499.bytes()).si()), "999kB");
+        assert_eq!(format!("{}", (999.kilobytes() + 999.bytes()).si()), "1MB");
+        assert_eq!(format!("{:.2}", (999.kilobytes() + 996.bytes()).si()), "1MB");
+        assert_eq!(format!("{:.3}", (999.kilobytes() + 996.bytes()).si()), "999.996kB");
+
+        let kib_1023_5 = 1023.kibibytes() + 512.bytes();
+        assert_eq!(format!("{:.0}", kib_1023_5.iec()), "1023KiB");
+        assert_eq!(format!("{:.0}", (kib_1023_5 + 1).iec()), "1MiB");
+        assert_eq!(format!("{:#.0}", (kib_1023_5 + 1).iec()), "1 MiB");
+        assert_eq!(format!("{:.1}", kib_1023_5.iec()), "1023.5KiB");
+        assert_eq!(format!("{}", (1.mebibytes() - 1).iec()), "1MiB");
+        assert_eq!(format!("{:04.1}", (1.mebibytes() - 1).iec()), "0001MiB");
+
+        assert_eq!(format!("{:.0}", 999.kilobytes() + 990.bytes()), "1MB");
+        assert_eq!(format!("{}", ByteUnit::max_value()), "16EiB");
+        assert_eq!(format!("{:.0}", ByteUnit::max_value()), "16EiB");
+        assert_eq!(format!("{:.4}", ByteUnit::max_value()), "16EiB");
+        assert_eq!(format!("{:.17}", ByteUnit::max_value()), "16EiB");
+        assert_eq!(format!("{:.20}", ByteUnit::max_value()), "15.9999999999999999991EiB");
+    }
+
+    #[test]
+    #[cfg_attr(any(feature = "default-si", feature = "default-iec"), ignore = "expects Auto")]
+    fn test_rounding_modes() {
+        extern crate std;
+        use std::format;
+        use crate::Rounding::*;
+
+        let value = 2.mebibytes() + 512.kibibytes();
+        for (rounding, expected) in [(Floor, "2MiB"), (Ceil, "3MiB"), (Nearest, "3MiB")] {
+            assert_eq!(format!("{:.0}", value.rounded(rounding)), expected);
+        }
+
+        let value = 2.mebibytes() + 511.kibibytes();
+        assert_eq!(format!("{:.0}", value.rounded(Floor)), "2MiB");
+        assert_eq!(format!("{:.0}", value.rounded(Ceil)), "3MiB");
+        assert_eq!(format!("{:.0}", value.rounded(Nearest)), "2MiB");
+        assert_eq!(format!("{:.0}", value), "2MiB");
+
+        let value = 1.megabytes() + 1.bytes();
+        assert_eq!(format!("{}", value.rounded(Floor)), "1.00MB");
+        assert_eq!(format!("{}", value.rounded(Ceil)), "1.01MB");
+        assert_eq!(format!("{:.6}", value.rounded(Ceil)), "1.000001MB");
+        assert_eq!(format!("{:#.0}", value.rounded(Ceil)), "2 MB");
+        assert_eq!(format!("{}", 1.megabytes().rounded(Ceil)), "1MB");
+
+        let value = 999.kilobytes() + 1.bytes();
+        assert_eq!(format!("{:.2}", value.si().rounded(Ceil)), "999.01kB");
+        assert_eq!(format!("{:.0}", value.si().rounded(Ceil)), "1MB");
+        assert_eq!(format!("{:.0}", (1.mebibytes() - 1).iec().rounded(Floor)), "1023KiB");
+        assert_eq!(format!("{:.0}", ByteUnit::max_value().rounded(Ceil)), "16EiB");
+        assert_eq!(format!("{:.0}", ByteUnit::max_value().rounded(Floor)), "15EiB");
+
+        let value = 1.kibibytes() + 1.bytes();
+        let fixed = value.display_in(ByteUnit::KiB);
+        assert_eq!(format!("{:.2}", fixed.rounded(Floor)), "1.00KiB");
+        assert_eq!(format!("{:.2}", fixed.rounded(Ceil)), "1.01KiB");
+        assert_eq!(format!("{:.2}", fixed), "1.00KiB");
+    }
+
+    #[test]
+    #[cfg_attr(any(feature = "default-si", feature = "default-iec"), ignore = "expects Auto")]
+    fn test_trimmed() {
+        extern crate std;
+        use std::format;
+        use crate::Rounding;
+
+        let value = 7.gibibytes() + 920.mebibytes();
+        assert_eq!(format!("{:.3}", value.trimmed()), "7.898GiB");
+        assert_eq!(format!("{:.1}", value.trimmed()), "7.9GiB");
+        assert_eq!(format!("{:04.2}", value.trimmed()), "0007.9GiB");
+        assert_eq!(format!("{:#.5}", value.trimmed()), "7.89844 GiB");
+
+        assert_eq!(format!("{}", 3.megabytes().trimmed()), "3MB");
+        assert_eq!(format!("{:.4}", 999.bytes().trimmed()), "999B");
+        assert_eq!(format!("{}", (1.megabytes() + 1.bytes()).trimmed()), "1MB");
+        assert_eq!(format!("{:.6}", (1.megabytes() + 1.bytes()).trimmed()), "1.000001MB");
+        assert_eq!(format!("{}", (1.mebibytes() - 1).iec().trimmed()), "1MiB");
+        assert_eq!(format!("{}", (1.megabytes() + 1.bytes()).rounded(Rounding::Ceil).trimmed()),
+            "1.01MB");
+
+        let fixed = 4.gibibytes().display_in(ByteUnit::MiB);
+        assert_eq!(format!("{}", fixed), "4096.00MiB");
+        assert_eq!(format!("{}", fixed.trimmed()), "4096MiB");
+        assert_eq!(format!("{:.3}", 10.kibibytes().display_in(ByteUnit::MiB).trimmed()), "0.01MiB");
+    }
+
+    #[test]
+    #[cfg_attr(any(feature = "default-si", feature = "default-iec"), ignore = "expects Auto")]
+    fn test_format_builder() {
+        extern crate std;
+        use std::format;
+        use crate::{Rounding, UnitFamily};
+
+        let value = 7.gibibytes() + 920.mebibytes();
+        for spec in [0, 1, 2, 5] {
+            assert_eq!(format!("{:.*}", spec, value.format()), format!("{:.*}", spec, value));
+            assert_eq!(format!("{:#.*}", spec, value.format()), format!("{:#.*}", spec, value));
+        }
+
+        assert_eq!(format!("{:.1}", value.format().precision(3)), "7.898GiB");
+        assert_eq!(format!("{}", value.format().precision(100)), "7.8984375000000000000GiB");
+        assert_eq!(format!("{:#}", value.format().space(false)), "7.90GiB");
+        assert_eq!(format!("{}", value.format().space(true)), "7.90 GiB");
+        assert_eq!(format!("{:04}", value.format().space(true)), "0007.90 GiB");
+        assert_eq!(format!("{}", value.si().family(UnitFamily::Auto)), "7.90GiB");
+        assert_eq!(format!("{}", value.format().unit(ByteUnit::GB).precision(1)), "8.5GB");
+        assert_eq!(format!("{}", value.display_in(ByteUnit::GB).family(UnitFamily::Iec)),
+            "7.90GiB");
+
+        let display = 1.megabytes() + 1.bytes();
+        let display = display.format().rounded(Rounding::Ceil).precision(0).space(true);
+        assert_eq!(format!("{:.5}", display), "2 MB");
+        assert_eq!(format!("{}", 3.megabytes().format().precision(4).trimmed()), "3MB");
+    }
+
+    #[test]
+    fn test_default_family() {
+        extern crate std;
+        use std::string::ToString;
+        use crate::UnitFamily;
+
+        let (si, iec) = (cfg!(feature = "default-si"), cfg!(feature = "default-iec"));
+        let (family, expected) = match (si, iec) {
+            (_, true) => (UnitFamily::Iec, "6.90MiB"),
+            (true, false) => (UnitFamily::Si, "7.23MB"),
+            (false, false) => (UnitFamily::Auto, "6.90MiB"),
+        };
+
+        let value = 7231.kilobytes();
+        assert_eq!(value.to_string(), expected);
+        assert_eq!(value.repr(), value.repr_preferred(family));
+        assert_eq!(value.repr(), value.repr_preferred(UnitFamily::Auto));
+
+        for value in [0, 1, 1000, 1024, 1536, 3_100_000, 7231 * 1024, u64::MAX] {
+            let value = value.bytes();
+            assert_eq!(value.repr(), value.repr_preferred(family));
+            assert_eq!(value.repr_exact().2, value.repr().2);
+        }
+    }
+
+    #[test]
+    fn test_repr_exact() {
+        let values = [0, 1, 999, 1000, 1023, 1024, 1025, 7231 * 1000, u64::MAX - 1, u64::MAX];
+        for value in values.iter().map(|&v| v.bytes()) {
+            let (whole, rem, suffix, unit) = value.repr_exact();
+            let (f_whole, _, f_suffix, f_unit) = value.repr();
+            assert_eq!((whole, suffix, unit), (f_whole, f_suffix, f_unit));
+            assert!(rem < unit.as_u64());
+            assert_eq!(whole as u128 * unit.as_u128() + rem as u128, value.as_u128());
+        }
+    }
+
+    #[test]
+    #[cfg_attr(any(feature = "default-si", feature = "default-iec"), ignore = "expects Auto")]
+    fn test_separators() {
+        extern crate std;
+        use std::format;
+
+        let bytes = |v: u64| v.bytes().format().unit(ByteUnit::B).precision(0).grouping(',');
+        assert_eq!(format!("{}", bytes(0)), "0B");
+        assert_eq!(format!("{}", bytes(999)), "999B");
+        assert_eq!(format!("{}", bytes(1000)), "1,000B");
+        assert_eq!(format!("{}", bytes(100_000)), "100,000B");
+        assert_eq!(format!("{}", bytes(1_000_000)), "1,000,000B");
+        assert_eq!(format!("{}", bytes(u64::MAX)), "18,446,744,073,709,551,615B");
+        assert_eq!(format!("{:1}", bytes(12)), "12B");
+        assert_eq!(format!("{:3}", bytes(12)), "012B");
+        assert_eq!(format!("{:4}", bytes(12)), "0,012B");
+        let display = 1234.bytes().display_in(ByteUnit::B).grouping('\u{202f}');
+        assert_eq!(format!("{:#.2}", display.decimal_point('٫')), "1\u{202f}234٫00 B");
+
+        let value = 7.gigabytes() + 58.mebibytes() + 3.kilobytes();
+        assert_eq!(format!("{:#}", value.format().decimal_point(',')), "7,06 GB");
+        assert_eq!(format!("{:.0}", value.format().decimal_point(',')), "7GB");
+        assert_eq!(format!("{:.2}", value.format().grouping('.')), "7.06GB");
+        assert_eq!(format!("{}", ByteUnit::max_value().format().grouping(',')), "16EiB");
+    }
+
+    #[test]
+    fn test_display_bits() {
+        extern crate std;
+        use std::format;
+        use crate::Rounding;
+
+        assert_eq!(format!("{}", 0.bytes().display_bits()), "0 bit");
+        assert_eq!(format!("{}", 125.bytes().display_bits()), "1 kbit");
+        assert_eq!(format!("{}", 124_999_999.bytes().display_bits()), "1 Gbit");
+        assert_eq!(format!("{:.8}", 124_999_999.bytes().display_bits()), "999.99999200 Mbit");
+        assert_eq!(format!("{}", 124_999_999.bytes().display_bits().rounded(Rounding::Floor)),
+            "999.99 Mbit");
+        assert_eq!(format!("{:.0}", ByteUnit::max_value().display_bits()), "148 Ebit");
+        assert_eq!(format!("{:05.1}", 1.kibibytes().display_bits()), "00008.2 kbit");
+        assert_eq!(format!("{}", 1.kibibytes().display_bits().unit(ByteUnit::B)), "1024.00 B");
+
+        for value in [1.bytes(), 125.bytes(), 10.megabytes(), 3.gigabytes() + 1, 1.exabytes()] {
+            for p in [0, 2, 5] {
+                let expected = format!("{:.*}", p, value.to_bits());
+                assert_eq!(format!("{:.*}", p, value.display_bits()), expected);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg_attr(any(feature = "default-si", feature = "default-iec"), ignore = "expects Auto")]
+    fn test_verbose() {
+        extern crate std;
+        use std::{format, string::ToString};
+        use crate::FloatToByteUnit;
+
+        assert_eq!(format!("{:.3}", 1.kibibytes().verbose()), "1 kibibyte");
+        assert_eq!(format!("{:.3}", (1.kibibytes() + 1).verbose()), "1.001 kibibytes");
+        assert_eq!(format!("{:.0}", (1.kibibytes() + 1).verbose()), "1 kibibyte");
+        assert_eq!(format!("{:.0}", 1.5.kibibytes().verbose()), "1 kibibyte");
+        assert_eq!(format!("{:.0}", 1.6.kibibytes().verbose()), "2 kibibytes");
+        assert_eq!(format!("{:.0}", (1.megabytes() - 1).verbose()), "1 megabyte");
+        assert_eq!(format!("{}", 2.bytes().verbose()), "2 bytes");
+        assert_eq!(format!("{:03}", 2.bytes().verbose()), "002 bytes");
+        assert_eq!(format!("{}", ByteUnit::max_value().verbose()), "16 exbibytes");
+        assert_eq!(format!("{}", 1.exbibytes().verbose().space(false)), "1 exbibyte");
+        assert_eq!(format!("{}", 1.exbibytes().verbose().long_names(false)), "1EiB");
+        assert_eq!(format!("{}", 1.kilobytes().iec().long_names(true)), "1000 bytes");
+        assert_eq!(format!("{}", 1.kilobytes().display_in(ByteUnit::kB).long_names(true)),
+            "1.00 kilobyte");
+
+        for value in [0, 1, 999, 1000, 1024, 1536, 1_000_000, 3 << 30] {
+            let value = value.bytes();
+            let display = value.verbose().precision(19).trimmed();
+            assert_eq!(display.to_string().parse::<ByteUnit>().unwrap(), value);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(any(feature = "default-si", feature = "default-iec"), ignore = "expects Auto")]
+    fn test_repr_preferred() {
+        use crate::UnitFamily::*;
+
+        let suffix = |v: ByteUnit, family| v.repr_preferred(family).2;
+        assert_eq!(suffix(0.bytes(), Iec), "B");
+        assert_eq!(suffix(999.bytes(), Iec), "B");
+        assert_eq!(suffix(1000.bytes(), Iec), "kB");
+        assert_eq!(suffix(1000.bytes(), Si), "kB");
+        assert_eq!(suffix(1024.bytes(), Si), "KiB");
+        assert_eq!(suffix(1024.bytes(), Iec), "KiB");
+        assert_eq!(suffix(1025.bytes(), Si), "kB");
+        assert_eq!(suffix(1025.bytes(), Iec), "KiB");
+        assert_eq!(suffix(2048.kilobytes(), Iec), "MiB");
+        assert_eq!(suffix(2048.kilobytes(), Si), "MB");
+        assert_eq!(suffix(7.exbibytes(), Si), "EiB");
+        assert_eq!(suffix(ByteUnit::max_value(), Si), "EB");
+        assert_eq!(suffix(ByteUnit::max_value(), Iec), "EiB");
+        assert_eq!(suffix(7231.kilobytes(), Auto), "MiB");
+    }
+
+    #[test]
+    fn test_raw_comparison() {
+        const ORDERING: core::cmp::Ordering = ByteUnit::KiB.cmp_bytes(1000);
+        assert_eq!(ORDERING, core::cmp::Ordering::Greater);
+
+        let values = [0, 1, 1000, 1024, u64::MAX - 1, u64::MAX];
+        for &a in &values {
+            for &b in &values {
+                assert_eq!(a.bytes().cmp_bytes(b), a.bytes().cmp(&b.bytes()));
+                assert_eq!(a.bytes().eq_bytes(b), a.bytes() == b);
+
+                let (a, b) = (a.bytes(), b.bytes());
+                assert_eq!(a.const_cmp(b), a.cmp(&b));
+                assert_eq!(a.const_eq(b), a == b);
+                assert_eq!(a.const_lt(b), a < b);
+                assert_eq!(a.const_le(b), a <= b);
+                assert_eq!(a.const_gt(b), a > b);
+                assert_eq!(a.const_ge(b), a >= b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_unit_suffix() {
+        extern crate std;
+        use std::format;
+
+        const MIB: Option<&str> = ByteUnit::MiB.suffix();
+        assert_eq!(MIB, Some("MiB"));
+
+        let units = ByteUnit::units();
+        assert!(units.windows(2).all(|w| w[0].0 < w[1].0));
+        for &(unit, suffix, name) in units {
+            assert_eq!(unit.suffix(), Some(suffix));
+            assert_eq!(unit.unit_name(), Some(name));
+            assert_eq!(crate::parse::unit_name(suffix), Some(name));
+            assert_eq!(format!("1{}", suffix).parse::<ByteUnit>().unwrap(), unit);
+            assert_eq!(format!("1 {}", name).parse::<ByteUnit>().unwrap(), unit);
+        }
+
+        assert_eq!(0.bytes().suffix(), None);
+        assert_eq!((ByteUnit::kB + 1).suffix(), None);
+        assert_eq!((ByteUnit::EiB - 1).unit_name(), None);
+        assert_eq!(ByteUnit::ZB.suffix(), None);
+    }
+
+    #[test]
+    #[cfg_attr(any(feature = "default-si", feature = "default-iec"), ignore = "expects Auto")]
+    fn test_significant() {
+        extern crate std;
+        use std::format;
+
+        assert_eq!(format!("{}", 0.bytes().significant(3)), "0B");
+        assert_eq!(format!("{}", 999.bytes().significant(1)), "999B");
+        assert_eq!(format!("{}", 1536.bytes().significant(0)), "1KiB");
+        assert_eq!(format!("{}", 1536.bytes().significant(1)), "1KiB");
+        assert_eq!(format!("{}", 1537.bytes().significant(1)), "2KiB");
+        assert_eq!(format!("{}", 1536.bytes().significant(5)), "1.5000KiB");
+        assert_eq!(format!("{}", 1536.bytes().significant(5).trimmed()), "1.5KiB");
+        assert_eq!(format!("{}", 1536.bytes().significant(40)), "1.5000000000000000000KiB");
+        assert_eq!(format!("{}", (1.mebibytes() - 1).significant(3)), "1.05MB");
+        assert_eq!(format!("{}", (1.mebibytes() - 1).significant(7)), "1.048575MB");
+        assert_eq!(format!("{:#.0}", 1536.kibibytes().significant(2)), "1.5 MiB");
+        assert_eq!(format!("{}", 1234.bytes().display_bits().significant(3)), "9.87 kbit");
+        assert_eq!(format!("{}", 125_000_499.bytes().display_bits().significant(2)), "1.0 Gbit");
+        assert_eq!(format!("{}", ByteUnit::max_value().significant(2)), "16EiB");
+    }
+
+    #[test]
+    fn test_from_f64_rounding() {
+        use crate::{FloatToByteUnit, Rounding::*};
+
+        assert_eq!(ByteUnit::from_f64(0.5, Nearest), 1);
+
+        let largest_below_max = 18_446_744_073_709_549_568.0;
+        for rounding in [Floor, Ceil, Nearest] {
+            assert_eq!(ByteUnit::from_f64(0.0, rounding), 0);
+            assert_eq!(ByteUnit::from_f64(-0.0, rounding), 0);
+            assert_eq!(ByteUnit::from_f64(-1.5, rounding), 0);
+            assert_eq!(ByteUnit::from_f64(f64::NEG_INFINITY, rounding), 0);
+            assert_eq!(ByteUnit::from_f64(9007199254740993.0, rounding), 1u64 << 53);
+            assert_eq!(ByteUnit::from_f64(largest_below_max, rounding), largest_below_max as u64);
+            assert_eq!(ByteUnit::from_f64(u64::MAX as f64, rounding), ByteUnit::max_value());
+            assert_eq!(ByteUnit::from_f64(f64::MAX, rounding), ByteUnit::max_value());
+        }
+
+        assert_eq!(ByteUnit::from_f64(f64::MIN_POSITIVE, Floor), 0);
+        assert_eq!(ByteUnit::from_f64(f64::MIN_POSITIVE, Ceil), 1);
+        assert_eq!(ByteUnit::from_f64(f64::MIN_POSITIVE, Nearest), 0);
+        assert_eq!(ByteUnit::from_f64(4503599627370495.5, Floor), 4503599627370495u64);
+        assert_eq!(ByteUnit::from_f64(4503599627370495.5, Ceil), 4503599627370496u64);
+        assert_eq!(ByteUnit::from_f64(4503599627370495.5, Nearest), 4503599627370496u64);
+        assert_eq!(ByteUnit::from_f64(2.4999999999999996, Nearest), 2);
+        assert_eq!(2.9f32.bytes(), ByteUnit::from_f64(2.9f32 as f64, Floor));
+    }
+
+    #[test]
+    fn test_byte_order_round_trip() {
+        let mid = 7.gibibytes() + 920.mebibytes() + 3;
+        for value in [ByteUnit::ZERO, mid, ByteUnit::max_value()] {
+            assert_eq!(value.to_le_bytes(), value.as_u64().to_le_bytes());
+            assert_eq!(value.to_be_bytes(), value.as_u64().to_be_bytes());
+            assert_eq!(ByteUnit::from_le_bytes(value.to_le_bytes()), value);
+            assert_eq!(ByteUnit::from_be_bytes(value.to_be_bytes()), value);
         }
+
+        assert_eq!(ByteUnit::ZERO.to_le_bytes(), [0; 8]);
+        assert_eq!(ByteUnit::max_value().to_be_bytes(), [0xff; 8]);
+        assert_eq!(mid.to_le_bytes(), [3, 0, 128, 249, 1, 0, 0, 0]);
+        assert_eq!(mid.to_be_bytes(), [0, 0, 0, 1, 249, 128, 0, 3]);
+    }
+
+    #[test]
+    #[cfg_attr(any(feature = "default-si", feature = "default-iec"), ignore = "expects Auto")]
+    fn test_pad_whole() {
+        extern crate std;
+        use std::format;
+
+        let value = 999.kilobytes() + 990.bytes();
+        assert_eq!(format!("{:04.2}", value.format().pad_whole(true)), "0976.55KiB");
+
+        let display = value.format().pad_whole(false);
+        assert_eq!(format!("{:.2}", display), "976.55KiB");
+        assert_eq!(format!("{:9.2}", display), "976.55KiB");
+        assert_eq!(format!("{:10.2}", display), " 976.55KiB");
+        assert_eq!(format!("{:>10.2}", display), " 976.55KiB");
+        assert_eq!(format!("{:<10.2}", display), "976.55KiB ");
+        assert_eq!(format!("{:^12.2}", display), " 976.55KiB  ");
+        assert_eq!(format!("{:-^11.2}", display), "-976.55KiB-");
+        assert_eq!(format!("{:010.2}", display), " 976.55KiB");
+
+        let bytes = |v: u64| v.bytes().format().unit(ByteUnit::B).precision(0).grouping(',');
+        assert_eq!(format!("{:8}", bytes(1234).pad_whole(false)), "  1,234B");
+        let display = 1.bytes().format().long_names(true).pad_whole(false);
+        assert_eq!(format!("{:8}", display), "  1 byte");
+        let display = 1536.bytes().format().decimal_point('٫').pad_whole(false);
+        assert_eq!(format!("{:9.2}", display), "  1٫50KiB");
     }
 }
@@ -0,0 +1,211 @@
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::ByteUnit;
+
+/// Any `u64` number of bytes.
+impl<'a> Arbitrary<'a> for ByteUnit {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u64::arbitrary(u).map(ByteUnit)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        u64::size_hint(depth)
+    }
+}
+
+/// The suffixes and unit names accepted by the default parser, in their
+/// canonical case. The empty suffix denotes bytes.
+const SUFFIXES: &[&str] = &[
+    "", "B", "kB", "KiB", "MB", "MiB", "GB", "GiB", "TB", "TiB", "PB", "PiB",
+    "EB", "EiB", "ZB", "ZiB", "YB", "YiB",
+    "byte", "kilobyte", "kibibyte", "megabyte", "mebibyte", "gigabyte",
+    "gibibyte", "terabyte", "tebibyte", "petabyte", "pebibyte", "exabyte",
+    "exbibyte", "zettabyte", "zebibyte", "yottabyte", "yobibyte",
+];
+
+/// The number in a [`ValidStr`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Number {
+    /// `{whole}.{frac}e{exp}`, with `frac` as `(zeros, digits)` and the whole
+    /// part optionally grouped in thousands by `_`.
+    Decimal { whole: u32, grouped: bool, frac: Option<(u8, u16)>, exp: Option<u8> },
+    /// `0x{n}`, `0o{n}`, or `0b{n}` for a radix of `16`, `8`, or `2`.
+    Radix(u32, u8),
+}
+
+/// The case of the suffix in a [`ValidStr`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Case {
+    Canonical,
+    Lower,
+    Upper,
+}
+
+/// An arbitrary string accepted by `ByteUnit`'s `FromStr` implementation.
+///
+/// A `ValidStr` is generated from [`Unstructured`] data via its
+/// [`Arbitrary`] implementation and covers the entire default grammar:
+/// decimal numbers with `_` separators, fractions, and exponents; hexadecimal,
+/// octal, and binary numbers; and every unit suffix and name in any case. Its
+/// `Display` implementation writes the string, which always parses
+/// successfully, making properties like parse/display round-trips easy to
+/// state.
+///
+/// # Example
+///
+/// ```rust
+/// use arbitrary::{Arbitrary, Unstructured};
+/// use ubyte::{ByteUnit, arbitrary::ValidStr};
+///
+/// let data = [0x5a; 64];
+/// let mut u = Unstructured::new(&data);
+/// let string = ValidStr::arbitrary(&mut u).unwrap().to_string();
+/// assert!(string.parse::<ByteUnit>().is_ok());
+///
+/// let value = ByteUnit::arbitrary(&mut u).unwrap();
+/// assert!(value.to_string().parse::<ByteUnit>().is_ok());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ValidStr {
+    plus: bool,
+    number: Number,
+    spaces: u8,
+    suffix: &'static str,
+    plural: bool,
+    case: Case,
+}
+
+impl<'a> Arbitrary<'a> for ValidStr {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let suffix = *u.choose(SUFFIXES)?;
+        let is_byte = suffix.is_empty() || suffix.eq_ignore_ascii_case("b") || suffix == "byte";
+        let number = match u.int_in_range(0..=3)? {
+            // A byte can't be fractional, so neither fractions nor exponents,
+            // which may make the value fractional, are generated for bytes.
+            0 if !is_byte => Number::Decimal {
+                whole: u.arbitrary()?,
+                grouped: u.arbitrary()?,
+                frac: Some((u.int_in_range(0..=5)?, u.arbitrary()?)),
+                exp: Some(u.int_in_range(0..=3)?).filter(|_| u.arbitrary().unwrap_or(false)),
+            },
+            0 | 1 => Number::Decimal {
+                whole: u.arbitrary()?,
+                grouped: u.arbitrary()?,
+                frac: None,
+                exp: None,
+            },
+            _ => Number::Radix(u.arbitrary()?, *u.choose(&[2, 8, 16])?),
+        };
+
+        // A radix number or a suffix beginning with `e`, which could be read
+        // as an exponent, must be separated by whitespace.
+        let needs_space = !suffix.is_empty()
+            && (matches!(number, Number::Radix(..)) || suffix.starts_with(['e', 'E']));
+
+        Ok(ValidStr {
+            plus: u.arbitrary()?,
+            number,
+            spaces: u.int_in_range(needs_space as u8..=2 * !suffix.is_empty() as u8)?,
+            suffix,
+            plural: suffix.len() > 3 && u.arbitrary()?,
+            case: *u.choose(&[Case::Canonical, Case::Lower, Case::Upper])?,
+        })
+    }
+}
+
+impl core::fmt::Display for ValidStr {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.plus {
+            f.write_str("+")?;
+        }
+
+        match self.number {
+            Number::Decimal { whole, grouped, frac, exp } => {
+                match (grouped, whole) {
+                    (true, 1_000_000_000..) => write!(f, "{}_{:03}_{:03}_{:03}",
+                        whole / 1_000_000_000, whole / 1_000_000 % 1000,
+                        whole / 1000 % 1000, whole % 1000)?,
+                    (true, 1_000_000..) => write!(f, "{}_{:03}_{:03}",
+                        whole / 1_000_000, whole / 1000 % 1000, whole % 1000)?,
+                    (true, 1000..) => write!(f, "{}_{:03}", whole / 1000, whole % 1000)?,
+                    _ => write!(f, "{}", whole)?,
+                }
+
+                if let Some((zeros, digits)) = frac {
+                    write!(f, ".{:0width$}", digits, width = zeros as usize + 1)?;
+                }
+
+                if let Some(exp) = exp {
+                    write!(f, "e{}", exp)?;
+                }
+            }
+            Number::Radix(n, 2) => write!(f, "{:#b}", n)?,
+            Number::Radix(n, 8) => write!(f, "{:#o}", n)?,
+            Number::Radix(n, _) => write!(f, "{:#x}", n)?,
+        }
+
+        for _ in 0..self.spaces {
+            f.write_str(" ")?;
+        }
+
+        for c in self.suffix.chars().chain(self.plural.then_some('s')) {
+            let c = match self.case {
+                Case::Canonical => c,
+                Case::Lower => c.to_ascii_lowercase(),
+                Case::Upper => c.to_ascii_uppercase(),
+            };
+
+            write!(f, "{}", c)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod arbitrary_tests {
+    extern crate std;
+
+    use std::string::ToString;
+    use std::vec::Vec;
+
+    use arbitrary::{Arbitrary, Unstructured};
+
+    use super::ValidStr;
+    use crate::ByteUnit;
+
+    /// Deterministic pseudo-random bytes via xorshift.
+    fn random_bytes(seed: u64, len: usize) -> Vec<u8> {
+        let mut state = seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1;
+        (0..len).map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state as u8
+        }).collect()
+    }
+
+    #[test]
+    fn test_arbitrary_byte_unit() {
+        let data = 0x0102_0304_0506_0708u64.to_le_bytes();
+        let value = ByteUnit::arbitrary(&mut Unstructured::new(&data)).unwrap();
+        assert_eq!(value, 0x0102_0304_0506_0708u64);
+        assert_eq!(ByteUnit::size_hint(0), (8, Some(8)));
+    }
+
+    #[test]
+    fn test_valid_str_parses() {
+        for seed in 0..20_000 {
+            let data = random_bytes(seed, 64);
+            let mut u = Unstructured::new(&data);
+            let string = ValidStr::arbitrary(&mut u).unwrap().to_string();
+            if let Err(e) = string.parse::<ByteUnit>() {
+                panic!("{:?} failed to parse: {:?}", string, e);
+            }
+        }
+
+        // Even exhausted data produces a valid string.
+        let string = ValidStr::arbitrary(&mut Unstructured::new(&[])).unwrap().to_string();
+        assert_eq!(string.parse::<ByteUnit>().unwrap(), 0);
+    }
+}
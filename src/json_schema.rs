@@ -0,0 +1,85 @@
+extern crate alloc;
+
+use alloc::borrow::Cow;
+
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+use crate::ByteUnit;
+
+/// A regular expression matching the strings accepted by `ByteUnit`'s
+/// `FromStr` implementation. The suffix, if any, is additionally required to
+/// be a known unit.
+const PATTERN: &str = "^\\+?(0x[0-9a-fA-F]+|0o[0-7]+|0b[01]+|\
+    [0-9]+(_[0-9]+)*(\\.[0-9]+(_[0-9]+)*)?([eE][+-]?[0-9]+)?) *[a-zA-Z]*$";
+
+const DESCRIPTION: &str = "A number of bytes as an unsigned integer or as a \
+    string with an optional, case-insensitive unit suffix, such as \"512\", \
+    \"1.5 MiB\", \"10kb\", or \"5 megabytes\". A string matches the regular \
+    expression `^\\+?(0x[0-9a-fA-F]+|0o[0-7]+|0b[01]+|[0-9]+(_[0-9]+)*\
+    (\\.[0-9]+(_[0-9]+)*)?([eE][+-]?[0-9]+)?) *[a-zA-Z]*$` where the suffix, \
+    if any, is an SI unit suffix from \"b\" to \"yib\" or a singular or plural \
+    unit name from \"byte\" to \"yobibytes\".";
+
+/// Describes a `ByteUnit` as either an unsigned integer or a string matching
+/// the byte unit grammar, mirroring the forms accepted by its `Deserialize`
+/// implementation.
+impl JsonSchema for ByteUnit {
+    fn schema_name() -> Cow<'static, str> {
+        "ByteUnit".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "ubyte::ByteUnit".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "description": DESCRIPTION,
+            "oneOf": [
+                { "type": "integer", "format": "uint64", "minimum": 0 },
+                { "type": "string", "pattern": PATTERN }
+            ]
+        })
+    }
+}
+
+#[cfg(test)]
+mod schema_tests {
+    use crate::ByteUnit;
+
+    #[test]
+    fn test_schema() {
+        let schema = schemars::schema_for!(ByteUnit);
+        let value = schema.as_value();
+        assert_eq!(value["title"], "ByteUnit");
+        assert_eq!(value["oneOf"][0]["type"], "integer");
+        assert_eq!(value["oneOf"][0]["minimum"], 0);
+        assert_eq!(value["oneOf"][1]["type"], "string");
+        assert_eq!(value["oneOf"][1]["pattern"], super::PATTERN);
+        assert!(super::DESCRIPTION.contains(&super::PATTERN[1..super::PATTERN.len() - 1]));
+    }
+
+    #[test]
+    fn test_schema_pattern() {
+        let regex = regex::Regex::new(super::PATTERN).unwrap();
+        let valid = [
+            "512", "1.5 MiB", "10kb", "+1e3 B", "0x1000", "0x10 B", "0o17kb", "0b101",
+            "1_048_576", "5 megabytes", "1.5_0kb", "1.5e-1kb", "1  kib", "9.5e3KiB",
+        ];
+
+        for s in valid {
+            assert!(regex.is_match(s), "{:?} should match", s);
+            assert!(s.parse::<ByteUnit>().is_ok(), "{:?} should parse", s);
+        }
+
+        let invalid = [
+            "", " 1KiB", "1KiB ", "1,024", ".5kb", "5.kb", "0X10", "1\tkib", "0x_10",
+            "-1", "kb", "1 2 kb",
+        ];
+
+        for s in invalid {
+            assert!(!regex.is_match(s), "{:?} shouldn't match", s);
+            assert!(s.parse::<ByteUnit>().is_err(), "{:?} shouldn't parse", s);
+        }
+    }
+}
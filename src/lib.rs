@@ -4,6 +4,7 @@
 //! `#![no_std]` library for byte units.
 //!
 //! ```rust
+//! # #[cfg(not(any(feature = "default-si", feature = "default-iec")))] {
 //! use ubyte::{ByteUnit, ToByteUnit};
 //!
 //! // Constructors and associated units for all SI units up to exbibyte.
@@ -37,41 +38,126 @@
 //! assert_eq!("1.5 MiB".parse::<ByteUnit>().unwrap(), 1.mebibytes() + 512.kibibytes());
 //! assert_eq!("0.2MB".parse::<ByteUnit>().unwrap(), 200.kilobytes());
 //! assert_eq!("7.25 gb".parse::<ByteUnit>().unwrap(), 7.gigabytes() + 250.megabytes());
+//! # }
 //! ```
 //!
 //! # Overview
 //!
 //! * [`ByteUnit`] constructors -- [`ByteUnit::Byte`] and friends -- for all SI
-//! units of bytes up to the exbibyte are provided; all constructors are `const`
-//! and saturating. Associated constants -- [`ByteUnit::B`] and friends -- for
-//! `1`-valued units are provided. Saturating arithmetic operations between
-//! `ByteUnit` and all integers types are implemented. `From<{integer}> for
-//! ByteUnit` for all integer types is implemented. `From<ByteUnit> for {u64,
-//! u128}>` is implemented.
+//!   units of bytes up to the exbibyte are provided; all constructors are `const`
+//!   and saturating. Associated constants -- [`ByteUnit::B`] and friends -- for
+//!   `1`-valued units are provided. Saturating arithmetic operations between
+//!   `ByteUnit` and all integers types are implemented, as is a saturating
+//!   [`Sum`](core::iter::Sum) of `ByteUnit`s. `From<{integer}> for
+//!   ByteUnit` and `From<NonZero{unsigned}> for ByteUnit` for all integer
+//!   types is implemented. `From<ByteUnit> for {u64, u128}>` is implemented,
+//!   as is the fallible `TryFrom<ByteUnit> for {u8, u16, u32, usize,
+//!   NonZeroU64}`.
 //!
 //! * [`ToByteUnit`] provides human-friendly methods on all integer types for
-//! converting into a `ByteUnit`: [`512.kilobytes()`](ToByteUnit::kilobytes).
+//!   converting into a `ByteUnit`: [`512.kilobytes()`](ToByteUnit::kilobytes).
+//!   [`FloatToByteUnit`] provides the same on floats: `2.5.megabytes()`.
+//!
+//! * [`BitUnit`] and [`ToBitUnit`] are the bit-denominated siblings of
+//!   `ByteUnit` and `ToByteUnit`: [`100.megabits().to_bytes()`](BitUnit::to_bytes)
+//!   is `12.5MB`. [`ByteUnit::to_bits()`] converts in the other direction.
+//!
+//! * [`ByteRate`] is a `ByteUnit` per `Duration`, constructed with
+//!   [`ByteUnit::per()`] or parsed from strings like `10MB/s` or `1GiB/min`.
+//!
+//! * [`ByteDelta`] is a signed difference between `ByteUnit`s, constructed
+//!   with [`ByteUnit::delta()`] and applied with [`ByteUnit::apply()`].
 //!
 //! * The [`Display`](struct.ByteUnit.html#impl-Display) implementation displays
-//! `ByteUnit`s in a human-friendly format. For truly custom printing,
-//! [`ByteUnit::repr()`] splits a value into its minimal components.
+//!   `ByteUnit`s in a human-friendly format. For truly custom printing,
+//!   [`ByteUnit::repr()`] splits a value into its minimal components. The
+//!   `default-si` and `default-iec` features make both prefer SI or IEC units,
+//!   respectively, throughout a build; `default-iec` wins if both are enabled.
+//!   Only binaries should enable these features: a library that does so
+//!   changes the output of every crate in the dependency graph.
 //!
 //! * The [`FromStr`](struct.ByteUnit.html#impl-FromStr) implementation parses
-//! byte units in a case-free manner: `1B` or `1b` or `1 b` => `1.bytes()`.
+//!   byte units in a case-free manner: `1B` or `1b` or `1 b` => `1.bytes()`.
+//!   [`ParseOptions`] and [`ByteUnit::parse_with()`] extend or restrict the
+//!   accepted grammar. `TryFrom<&str>` is implemented as well, as is
+//!   `TryFrom<String>` with the `alloc` feature enabled.
 //!
 //! * With the `serde` feaure enabled (disabled by default), `ByteUnit`
-//! implements [`Deserialize`](struct.ByteUnit.html#impl-Deserialize<%27de>)
-//! from strings and all integer types as well as
-//! [`Serialize`](struct.ByteUnit.html#impl-Serialize) into a `u64`.
+//!   implements [`Deserialize`](struct.ByteUnit.html#impl-Deserialize<%27de>)
+//!   from strings, all integer types, and floats as well as
+//!   [`Serialize`](struct.ByteUnit.html#impl-Serialize) into a `u64`.
+//!   [`serde::as_str`](serde/as_str/index.html),
+//!   [`serde::string`](serde/string/index.html), and
+//!   [`serde::unit`](serde/unit/index.html) serialize into a human-readable
+//!   string instead.
+//!
+//! * With the `schemars` feature enabled (disabled by default), `ByteUnit`
+//!   implements `JsonSchema`, describing both the integer and string forms.
+//!
+//! * With the `arbitrary` feature enabled (disabled by default), `ByteUnit`
+//!   implements `Arbitrary` for fuzzing, and
+//!   [`arbitrary::ValidStr`](arbitrary/struct.ValidStr.html) generates strings
+//!   the parser accepts.
+//!
+//! * With the `num-traits` feature enabled (disabled by default), `ByteUnit`
+//!   implements `num_traits`' `Zero`, `One`, and `Bounded`.
+//!
+//! * With the `borsh` feature enabled (disabled by default), `ByteUnit`
+//!   implements `BorshSerialize` and `BorshDeserialize` as a `u64`.
+//!
+//! * With the `rkyv` feature enabled (disabled by default), `ByteUnit`
+//!   implements `rkyv`'s `Archive`, `Serialize`, and `Deserialize`. The
+//!   archived form, `ArchivedByteUnit`, is a little-endian `u64` readable in
+//!   place with `ArchivedByteUnit::as_u64()`.
 //!
 //! * All operations -- constructors, arithmetic -- saturate. Overflow,
-//! underflow, divide-by-zero, and mod-by-zero are impossible.
+//!   underflow, divide-by-zero, and mod-by-zero are impossible.
 
-mod arithmetic;
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[macro_use]
 mod byte_unit;
+mod bit_unit;
+mod byte_rate;
+mod byte_delta;
+mod non_zero;
+mod arithmetic;
 mod parse;
 #[cfg(feature = "serde")]
 mod ser_de;
+#[cfg(feature = "schemars")]
+mod json_schema;
+#[cfg(feature = "arbitrary")]
+mod fuzz;
+#[cfg(feature = "num-traits")]
+mod num;
+#[cfg(feature = "borsh")]
+mod borsh_ser_de;
+#[cfg(feature = "rkyv")]
+mod archive;
+
+pub use byte_unit::{ByteUnit, ToByteUnit, FloatToByteUnit, FromFloatError};
+pub use byte_unit::{UnitDisplay, Rounding, UnitFamily};
+pub use bit_unit::{BitUnit, ToBitUnit};
+pub use byte_rate::ByteRate;
+pub use byte_delta::ByteDelta;
+pub use non_zero::NonZeroByteUnit;
+pub use parse::{Error, ParseOptions};
+#[cfg(feature = "rkyv")]
+pub use byte_unit::ArchivedByteUnit;
+
+/// Helpers for use with `serde`'s `#[serde(with = "...")]` attribute.
+#[cfg(feature = "serde")]
+pub mod serde {
+    pub use crate::ser_de::{as_str, string, unit};
+}
 
-pub use byte_unit::{ByteUnit, ToByteUnit};
-pub use parse::Error;
+/// Arbitrary byte unit strings for fuzzing and property testing.
+///
+/// `ByteUnit` implements `arbitrary::Arbitrary`, generating any `u64` number of
+/// bytes, when the `arbitrary` feature is enabled.
+#[cfg(feature = "arbitrary")]
+pub mod arbitrary {
+    pub use crate::fuzz::ValidStr;
+}
@@ -0,0 +1,208 @@
+use core::convert::TryFrom;
+use core::num::{NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize};
+use core::num::TryFromIntError;
+use core::ops::{Add, Sub, Mul, Div, Rem};
+
+use crate::ByteUnit;
+
+/// A [`ByteUnit`] that is known to be non-zero.
+///
+/// A `NonZeroByteUnit` is to a `ByteUnit` what a [`NonZeroU64`] is to a `u64`:
+/// it enforces a strictly positive number of bytes, such as a chunk or page
+/// size, in the type system. It has the same layout as a `ByteUnit`, and
+/// `Option<NonZeroByteUnit>` is the same size as a `ByteUnit`.
+///
+/// A `NonZeroByteUnit` is constructed with [`NonZeroByteUnit::new()`] or
+/// [`ByteUnit::try_nonzero()`] and converts into a `ByteUnit` with
+/// [`NonZeroByteUnit::get()`] or `From`. Because arithmetic can reach zero, all
+/// arithmetic operations on a `NonZeroByteUnit` return a `ByteUnit`. Dividing a
+/// `ByteUnit` by a `NonZeroByteUnit` never divides by zero and thus never
+/// saturates.
+///
+/// # Example
+///
+/// ```rust
+/// use ubyte::{ByteUnit, NonZeroByteUnit, ToByteUnit};
+///
+/// const PAGE: NonZeroByteUnit = match NonZeroByteUnit::new(4096) {
+///     Some(page) => page,
+///     None => panic!("page size must be non-zero"),
+/// };
+///
+/// assert_eq!(PAGE.get(), 4.kibibytes());
+/// assert_eq!(1.mebibytes() / PAGE, 256);
+/// assert_eq!(PAGE - 4.kibibytes(), ByteUnit::ZERO);
+///
+/// assert!(0.bytes().try_nonzero().is_none());
+/// assert_eq!(1.kibibytes().try_nonzero().unwrap().to_string(), "1KiB");
+/// ```
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonZeroByteUnit(NonZeroU64);
+
+impl NonZeroByteUnit {
+    /// Constructs a `NonZeroByteUnit` representing `bytes` bytes if `bytes`
+    /// is non-zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{NonZeroByteUnit, ToByteUnit};
+    ///
+    /// assert_eq!(NonZeroByteUnit::new(512).unwrap().get(), 512.bytes());
+    /// assert!(NonZeroByteUnit::new(0).is_none());
+    /// ```
+    pub const fn new(bytes: u64) -> Option<NonZeroByteUnit> {
+        match NonZeroU64::new(bytes) {
+            Some(bytes) => Some(NonZeroByteUnit(bytes)),
+            None => None,
+        }
+    }
+
+    /// Returns `self` as a `ByteUnit`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{NonZeroByteUnit, ToByteUnit};
+    ///
+    /// assert_eq!(NonZeroByteUnit::new(1024).unwrap().get(), 1.kibibytes());
+    /// ```
+    pub const fn get(self) -> ByteUnit {
+        ByteUnit(self.0.get())
+    }
+
+    /// Returns the value of bytes represented by `self` as a `u64`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::NonZeroByteUnit;
+    ///
+    /// assert_eq!(NonZeroByteUnit::new(1024).unwrap().as_u64(), 1024);
+    /// ```
+    pub const fn as_u64(self) -> u64 {
+        self.0.get()
+    }
+}
+
+impl ByteUnit {
+    /// Returns `self` as a [`NonZeroByteUnit`] if `self` is non-zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteUnit, ToByteUnit};
+    ///
+    /// assert_eq!(1.kibibytes().try_nonzero().unwrap().get(), 1.kibibytes());
+    /// assert!(ByteUnit::ZERO.try_nonzero().is_none());
+    /// ```
+    pub const fn try_nonzero(self) -> Option<NonZeroByteUnit> {
+        NonZeroByteUnit::new(self.0)
+    }
+}
+
+impl From<NonZeroByteUnit> for ByteUnit {
+    #[inline(always)]
+    fn from(v: NonZeroByteUnit) -> Self {
+        v.get()
+    }
+}
+
+impl From<NonZeroByteUnit> for NonZeroU64 {
+    #[inline(always)]
+    fn from(v: NonZeroByteUnit) -> Self {
+        v.0
+    }
+}
+
+impl From<NonZeroU64> for NonZeroByteUnit {
+    #[inline(always)]
+    fn from(v: NonZeroU64) -> Self {
+        NonZeroByteUnit(v)
+    }
+}
+
+/// Fails if `v` is zero.
+impl TryFrom<ByteUnit> for NonZeroU64 {
+    type Error = TryFromIntError;
+
+    #[inline(always)]
+    fn try_from(v: ByteUnit) -> Result<Self, Self::Error> {
+        NonZeroU64::try_from(v.as_u64())
+    }
+}
+
+macro_rules! impl_from_non_zero {
+    ($($T:ty),*) => ($(
+        impl From<$T> for ByteUnit {
+            #[inline(always)]
+            fn from(v: $T) -> Self {
+                ByteUnit::from(v.get())
+            }
+        }
+    )*)
+}
+
+impl_from_non_zero!(NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize);
+
+macro_rules! impl_arith_op_on_non_zero {
+    ($($Trait:ident, $func:ident, $op:tt);*) => ($(
+        impl<T: Into<ByteUnit>> $Trait<T> for NonZeroByteUnit {
+            type Output = ByteUnit;
+
+            #[inline(always)]
+            fn $func(self, rhs: T) -> Self::Output {
+                self.get() $op rhs
+            }
+        }
+    )*)
+}
+
+impl_arith_op_on_non_zero!(Add, add, +; Sub, sub, -; Mul, mul, *; Div, div, /; Rem, rem, %);
+
+/// Display `self` exactly as the equivalent `ByteUnit` is displayed.
+impl core::fmt::Display for NonZeroByteUnit {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.get().fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod non_zero_tests {
+    use crate::{ByteUnit, ToByteUnit};
+
+    #[test]
+    fn test_non_zero() {
+        use crate::NonZeroByteUnit;
+
+        let four = NonZeroByteUnit::new(4).unwrap();
+        assert_eq!(core::mem::size_of::<Option<NonZeroByteUnit>>(), 8);
+        assert_eq!(ByteUnit::max_value() / four, u64::MAX / 4);
+        assert_eq!(ByteUnit::max_value() % four, 3);
+        assert_eq!(four + 1, 5);
+        assert_eq!(four - 5, 0);
+        assert_eq!(four * ByteUnit::max_value(), ByteUnit::max_value());
+        assert_eq!(four / 0, ByteUnit::max_value());
+        assert_eq!(four % 3, 1);
+        assert!(four.get() < 5.bytes());
+    }
+
+    #[test]
+    fn test_from_non_zero_ints() {
+        use core::convert::TryFrom;
+        use core::num::*;
+
+        assert_eq!(ByteUnit::from(NonZeroU8::new(u8::MAX).unwrap()), u8::MAX);
+        assert_eq!(ByteUnit::from(NonZeroU16::new(512).unwrap()), 512);
+        assert_eq!(ByteUnit::from(NonZeroU32::new(4096).unwrap()), 4.kibibytes());
+        assert_eq!(ByteUnit::from(NonZeroU64::new(u64::MAX).unwrap()), ByteUnit::max_value());
+        assert_eq!(ByteUnit::from(NonZeroU128::new(u128::MAX).unwrap()), ByteUnit::max_value());
+        assert_eq!(ByteUnit::from(NonZeroUsize::new(1).unwrap()), 1);
+        assert_eq!(1.kibibytes() + NonZeroU32::new(1).unwrap(), 1025);
+
+        assert_eq!(NonZeroU64::try_from(4.kibibytes()), Ok(NonZeroU64::new(4096).unwrap()));
+        assert!(NonZeroU64::try_from(0.bytes()).is_err());
+        assert_eq!(NonZeroU64::try_from(ByteUnit::max_value()).unwrap().get(), u64::MAX);
+    }
+}
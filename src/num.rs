@@ -0,0 +1,68 @@
+use num_traits::{Bounded, One, Zero};
+
+use crate::ByteUnit;
+
+/// [`ByteUnit::ZERO`] is the additive identity.
+impl Zero for ByteUnit {
+    #[inline(always)]
+    fn zero() -> Self {
+        ByteUnit::ZERO
+    }
+
+    #[inline(always)]
+    fn is_zero(&self) -> bool {
+        ByteUnit::is_zero(*self)
+    }
+}
+
+/// [`ByteUnit::B`] is the multiplicative identity.
+impl One for ByteUnit {
+    #[inline(always)]
+    fn one() -> Self {
+        ByteUnit::B
+    }
+}
+
+/// The bounds are [`ByteUnit::MIN`] and [`ByteUnit::max_value()`].
+impl Bounded for ByteUnit {
+    #[inline(always)]
+    fn min_value() -> Self {
+        ByteUnit::MIN
+    }
+
+    #[inline(always)]
+    fn max_value() -> Self {
+        ByteUnit::max_value()
+    }
+}
+
+#[cfg(test)]
+mod num_tests {
+    use num_traits::{Bounded, One, Zero};
+
+    use crate::{ByteUnit, ToByteUnit};
+
+    fn total<T: Zero + Copy>(values: &[T]) -> T {
+        values.iter().fold(T::zero(), |acc, &v| acc + v)
+    }
+
+    fn product<T: One + Copy>(values: &[T]) -> T {
+        values.iter().fold(T::one(), |acc, &v| acc * v)
+    }
+
+    #[test]
+    fn test_num_traits() {
+        assert_eq!(<ByteUnit as Zero>::zero(), 0);
+        assert!(Zero::is_zero(&0.bytes()));
+        assert!(!Zero::is_zero(&1.bytes()));
+        assert_eq!(<ByteUnit as One>::one(), 1);
+        assert_eq!(<ByteUnit as Bounded>::min_value(), 0);
+        assert_eq!(<ByteUnit as Bounded>::max_value(), u64::MAX);
+
+        assert_eq!(total::<ByteUnit>(&[]), 0);
+        assert_eq!(total(&[1.kibibytes(), 2.kibibytes()]), 3.kibibytes());
+        assert_eq!(total(&[ByteUnit::max_value(), 1.bytes()]), ByteUnit::max_value());
+        assert_eq!(product::<ByteUnit>(&[]), 1);
+        assert_eq!(product(&[2.bytes(), 512.bytes()]), 1.kibibytes());
+    }
+}
@@ -1,13 +1,25 @@
+use core::convert::TryFrom;
+use core::num::{IntErrorKind, ParseIntError};
+use core::ops::Range;
+use core::str::FromStr;
+
 use crate::ByteUnit;
 
 macro_rules! parse_suffix_fn {
-    ($($suffix:ident),*) => (
-        parse_suffix_fn!($($suffix, stringify!($suffix)),*);
+    ($($suffix:ident = $name:expr, $size:expr),*) => (
+        parse_suffix_fn!($($suffix, stringify!($suffix), $name, $size),*);
     );
-    ($($suffix:ident, $string:expr),*) => (
-        fn parse_suffix(string: &str) -> Option<ByteUnit> {
-            $(if string.eq_ignore_ascii_case($string) {
-                return Some(ByteUnit::$suffix);
+    ($($suffix:ident, $string:expr, $name:expr, $size:expr),*) => (
+        /// All suffixes and the exact number of bytes in their `1`-valued unit.
+        const SUFFIXES: &[(&str, u128)] = &[$(($string, $size)),*];
+
+        /// All suffixes and the singular name of their unit.
+        const UNIT_NAMES: &[(&str, &str)] = &[$(($string, $name)),*];
+
+        fn parse_si_suffix(string: &str, long_names: bool) -> Option<u128> {
+            $(if string.eq_ignore_ascii_case($string)
+                || (long_names && is_unit_name(string, $name)) {
+                return Some($size);
             })*
 
             None
@@ -15,10 +27,268 @@ macro_rules! parse_suffix_fn {
     );
 }
 
-parse_suffix_fn!(B, kB, KiB, MB, MiB, GB, GiB, TB, TiB, PB, PiB, EB, EiB);
+// Units beyond `EiB` exceed `u64::MAX`, so their sizes are given as `u128`s.
+parse_suffix_fn! {
+    B = "byte", 1,
+    kB = "kilobyte", 1_000, KiB = "kibibyte", 1 << 10,
+    MB = "megabyte", 1_000_000, MiB = "mebibyte", 1 << 20,
+    GB = "gigabyte", 1_000_000_000, GiB = "gibibyte", 1 << 30,
+    TB = "terabyte", 1_000_000_000_000, TiB = "tebibyte", 1 << 40,
+    PB = "petabyte", 1_000_000_000_000_000, PiB = "pebibyte", 1 << 50,
+    EB = "exabyte", 1_000_000_000_000_000_000, EiB = "exbibyte", 1 << 60,
+    ZB = "zettabyte", 1_000_000_000_000_000_000_000, ZiB = "zebibyte", 1 << 70,
+    YB = "yottabyte", 1_000_000_000_000_000_000_000_000, YiB = "yobibyte", 1 << 80
+}
+
+/// Parses the suffix `string` into the exact number of bytes in its `1`-valued
+/// unit as allowed by `opts`.
+fn parse_suffix(string: &str, opts: &ParseOptions) -> Option<u128> {
+    let unit = parse_si_suffix(string, opts.long_names)
+        .or_else(|| parse_shorthand_suffix(string).filter(|_| opts.shorthand))?;
+
+    if opts.default_binary {
+        if let Some(k) = (1..=8).find(|&k| unit == 1000u128.pow(k)) {
+            return Some(1 << (10 * k));
+        }
+    }
+
+    Some(unit)
+}
+
+/// Converts the `u128` number of bytes `value` into a `ByteUnit`, saturating.
+const fn saturate(value: u128) -> ByteUnit {
+    match value > u64::MAX as u128 {
+        true => ByteUnit::max_value(),
+        false => ByteUnit(value as u64),
+    }
+}
+
+/// Converts the `unit` parsed from `suffix`, if any, into bytes if it denotes
+/// bits as allowed by `opts`, returning the unit in bytes and a divisor to
+/// apply to the final value.
+///
+/// Every bit unit but the bit itself is a whole number of bytes and is thus
+/// converted exactly. A value in bits is instead divided by `8`, rounding
+/// toward zero.
+fn bits_to_bytes(unit: u128, suffix: Option<&str>, opts: &ParseOptions) -> (u128, u64) {
+    match suffix {
+        Some(suffix) if opts.bit_aware && suffix.ends_with('b') => match unit == 1 {
+            true => (unit, 8),
+            false => (unit / 8, 1),
+        },
+        _ => (unit, 1),
+    }
+}
+
+/// Returns the singular name of the unit with the exact `suffix`, such as
+/// `megabyte` for `MB`.
+pub(crate) fn unit_name(suffix: &str) -> Option<&'static str> {
+    UNIT_NAMES.iter().find(|(s, _)| *s == suffix).map(|(_, name)| *name)
+}
+
+/// Whether `string` is, case insensitively, the singular or plural `name`.
+pub(crate) fn is_unit_name(string: &str, name: &str) -> bool {
+    let singular = string.strip_suffix(|c| c == 's' || c == 'S').unwrap_or(string);
+    singular.eq_ignore_ascii_case(name)
+}
 
 fn is_suffix_char(c: char) -> bool {
-    "begikmpt ".contains(c.to_ascii_lowercase())
+    "begikmptyz \u{a0}".contains(c.to_ascii_lowercase())
+}
+
+/// Returns the index just past the whitespace in `bytes` beginning at `i`:
+/// either any number of ` ` characters or a single no-break space, `U+00A0`,
+/// as is common in copy-pasted text.
+const fn skip_space(bytes: &[u8], mut i: usize) -> usize {
+    if i + 1 < bytes.len() && bytes[i] == 0xC2 && bytes[i + 1] == 0xA0 {
+        return i + 2;
+    }
+
+    while i < bytes.len() && bytes[i] == b' ' {
+        i += 1;
+    }
+
+    i
+}
+
+/// Whether `c` may appear in a suffix after its first character. This is more
+/// permissive than [`is_suffix_char()`] to allow for unit names.
+fn is_suffix_continue_char(c: char) -> bool {
+    c.is_ascii_alphabetic() || c == ' '
+}
+
+/// Parses a single-letter shorthand suffix, case insensitively, into the
+/// corresponding binary unit size: `k` is `KiB`, `m` is `MiB`, and so on.
+fn parse_shorthand_suffix(string: &str) -> Option<u128> {
+    let power = match string.as_bytes() {
+        [b'k'] | [b'K'] => 1,
+        [b'm'] | [b'M'] => 2,
+        [b'g'] | [b'G'] => 3,
+        [b't'] | [b'T'] => 4,
+        [b'p'] | [b'P'] => 5,
+        [b'e'] | [b'E'] => 6,
+        [b'z'] | [b'Z'] => 7,
+        [b'y'] | [b'Y'] => 8,
+        _ => return None,
+    };
+
+    Some(1 << (10 * power))
+}
+
+/// Whether an `e` or `E` followed by `rest` begins an exponent as opposed to a
+/// suffix such as `EB` or `EiB`. With `shorthand`, a trailing `e` is a suffix.
+/// With `partial`, the exponent must be well-formed: an `e` followed by a sign
+/// and no digits is a suffix, so `1e+` begins with `1`.
+fn is_exponent_start(rest: &str, shorthand: bool, partial: bool) -> bool {
+    if partial {
+        let digits = rest.strip_prefix(['+', '-']).unwrap_or(rest);
+        return digits.starts_with(|c: char| c.is_ascii_digit());
+    }
+
+    match rest.chars().next() {
+        None => !shorthand,
+        Some(c) => c.is_ascii_digit() || c == '+' || c == '-',
+    }
+}
+
+/// Returns the length of the longest valid suffix, as allowed by `opts`, that
+/// begins `s`, if any. The longest suffixes, like `zettabytes`, are ten
+/// characters long.
+fn suffix_len(s: &str, opts: &ParseOptions) -> Option<usize> {
+    let letters = s.bytes().take(10).take_while(|b| b.is_ascii_alphabetic()).count();
+    (1..=letters).rev().find(|&n| parse_suffix(&s[..n], opts).is_some())
+}
+
+/// Whether `a` and `b` are equal ignoring ASCII case. This is
+/// `<[u8]>::eq_ignore_ascii_case()`, which isn't `const` before Rust 1.89.
+const fn eq_ignore_ascii_case(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < a.len() {
+        if !a[i].eq_ignore_ascii_case(&b[i]) {
+            return false;
+        }
+
+        i += 1;
+    }
+
+    true
+}
+
+/// Whether the `_` at byte index `i` of `s` sits between two digits.
+fn is_digit_separator(s: &str, i: usize) -> bool {
+    let bytes = s.as_bytes();
+    i > 0 && bytes[i - 1].is_ascii_digit()
+        && bytes.get(i + 1).map(|b| b.is_ascii_digit()).unwrap_or(false)
+}
+
+/// Whether the `,` at byte index `i` of `s` separates a group of one to three
+/// leading digits, or of exactly three digits following another `,`, from a
+/// group of exactly three digits.
+fn is_group_separator(s: &str, i: usize) -> bool {
+    let bytes = s.as_bytes();
+    let before = bytes[..i].iter().rev().take_while(|b| b.is_ascii_digit()).count();
+    let after = bytes[(i + 1)..].iter().take_while(|b| b.is_ascii_digit()).count();
+    let prev = i.checked_sub(before + 1).map(|j| bytes[j]);
+    let next = bytes.get(i + 1 + after).copied();
+    let valid_before = match prev {
+        None => (1..=3).contains(&before),
+        Some(b',') => before == 3,
+        Some(_) => false,
+    };
+
+    valid_before && after == 3 && next != Some(b'_')
+}
+
+/// Returns exactly `floor(0.{digits} * unit)`, ignoring `_` separators.
+///
+/// Digits are accumulated from least to most significant, so any number of
+/// digits is handled without overflow and trailing zeros have no effect.
+/// Flooring at every step is exact since `floor((a + x) / 10) == floor((a +
+/// floor(x)) / 10)` for integral `a`, and the accumulator never exceeds `unit`.
+fn frac_of_unit(digits: &str, unit: u128) -> u128 {
+    digits.bytes().rev().filter(|b| b.is_ascii_digit())
+        .fold(0u128, |acc, d| (acc + (d - b'0') as u128 * unit) / 10)
+}
+
+/// Returns exactly `floor({whole}.{frac}e{exp} * unit)`, saturating, ignoring
+/// `_` and `,` separators, and whether the value has a nonzero fractional
+/// part before `unit` is applied.
+///
+/// The decimal point is shifted by `exp` digits. The digits that end up to
+/// its left form the whole part while those to its right are accumulated as
+/// [`frac_of_unit()`] does. No more than `40` zeros are ever shifted in: `u128`
+/// saturates well before then, and the fraction of any unit is zero after.
+fn shift_of_unit(whole: &str, frac: &str, exp: i32, unit: u128) -> (u128, bool) {
+    const MAX_ZEROS: i64 = 40;
+
+    let digits = || whole.bytes().chain(frac.bytes())
+        .filter(|b| b.is_ascii_digit())
+        .map(|b| (b - b'0') as u128);
+
+    let count = digits().count() as i64;
+    let point = whole.bytes().filter(|b| b.is_ascii_digit()).count() as i64 + exp as i64;
+    let split = point.clamp(0, count) as usize;
+
+    let whole = digits().take(split)
+        .chain((0..(point - count).clamp(0, MAX_ZEROS)).map(|_| 0))
+        .fold(0u128, |acc, d| acc.saturating_mul(10).saturating_add(d));
+
+    let frac = digits().rev().take(count as usize - split)
+        .chain((0..(-point).clamp(0, MAX_ZEROS)).map(|_| 0))
+        .fold(0u128, |acc, d| (acc + d * unit) / 10);
+
+    let has_frac = digits().skip(split).any(|d| d != 0);
+    (whole.saturating_mul(unit).saturating_add(frac), has_frac)
+}
+
+/// Parses `digits`, which may contain `_` or `,` separators, as a `T`.
+fn parse_int<T>(digits: &str) -> Result<T, ParseIntError>
+    where T: FromStr<Err = ParseIntError> + TryFrom<u64>
+{
+    let is_separator = |c: char| c == '_' || c == ',';
+    if !digits.contains(is_separator) {
+        return digits.parse();
+    }
+
+    // `ParseIntError` can't be constructed directly, so we provoke one.
+    let overflow = || match T::from_str("999999999999999999999999") {
+        Err(e) => e,
+        Ok(_) => unreachable!("value exceeds `u64::MAX`"),
+    };
+
+    let mut value: u64 = 0;
+    for segment in digits.split(is_separator) {
+        let n: u64 = segment.parse()?;
+        value = match value {
+            0 => n,
+            _ => 10u64.checked_pow(segment.len() as u32)
+                .and_then(|p| value.checked_mul(p))
+                .and_then(|v| v.checked_add(n))
+                .ok_or_else(overflow)?
+        };
+    }
+
+    T::try_from(value).map_err(|_| overflow())
+}
+
+/// Returns the radix of the `0x`, `0o`, or `0b` prefix of `s` if the prefix is
+/// followed by a digit in that radix.
+fn radix_prefix(s: &str) -> Option<u32> {
+    let radix = match s.get(..2)? {
+        "0x" => 16,
+        "0o" => 8,
+        "0b" => 2,
+        _ => return None,
+    };
+
+    match s[2..].chars().next()?.is_digit(radix) {
+        true => Some(radix),
+        false => None,
+    }
 }
 
 /// Parsing error, as returned by
@@ -30,60 +300,867 @@ pub enum Error {
     Empty,
     /// Found unexpected character `.1` at byte index `.0`.
     Unexpected(usize, char),
-    /// A [`ByteUnit::B`] contained a fractional component.
-    FractionalByte,
-    /// The parsed byte unit suffix is unknown.
-    BadSuffix,
-    /// The whole part of the the number (`{whole}.{frac}`) was invalid.
-    BadWhole(core::num::ParseIntError),
-    /// The fractional part of the the number (`{whole}.{frac}`) was invalid.
-    BadFractional(core::num::ParseIntError),
+    /// A [`ByteUnit::B`] contained a fractional component spanning byte
+    /// indices `.0`.
+    FractionalByte(Range<usize>),
+    /// The byte unit suffix spanning byte indices `.0` is unknown.
+    BadSuffix(Range<usize>),
+    /// The whole part of the the number (`{whole}.{frac}`), spanning byte
+    /// indices `.0`, was invalid.
+    BadWhole(Range<usize>, ParseIntError),
+    /// The whole part of the number (`{whole}.{frac}`), spanning byte indices
+    /// `.0`, exceeds `u64::MAX`.
+    Overflow(Range<usize>),
+    /// The input was negative, as indicated by the `-` spanning byte indices
+    /// `.0`.
+    Negative(Range<usize>),
+    /// The fractional part of the the number (`{whole}.{frac}`), spanning byte
+    /// indices `.0`, was invalid.
+    BadFractional(Range<usize>, ParseIntError),
+    /// The exponent of the number (`{whole}.{frac}e{exp}`), spanning byte
+    /// indices `.0`, was invalid. An exponent of any magnitude is valid: the
+    /// value saturates.
+    BadExponent(Range<usize>, ParseIntError),
+    /// The time unit of a [`ByteRate`](crate::ByteRate), spanning byte
+    /// indices `.0`, was missing or unknown.
+    BadRate(Range<usize>),
 }
 
-impl core::str::FromStr for ByteUnit {
-    type Err = Error;
+impl Error {
+    /// Returns the byte indices of the input relevant to the error, if any.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::ByteUnit;
+    ///
+    /// let error = "1.5 KiX".parse::<ByteUnit>().unwrap_err();
+    /// assert_eq!(error.span(), Some(4..7));
+    ///
+    /// let error = "1?".parse::<ByteUnit>().unwrap_err();
+    /// assert_eq!(error.span(), Some(1..2));
+    ///
+    /// let error = "".parse::<ByteUnit>().unwrap_err();
+    /// assert_eq!(error.span(), None);
+    /// ```
+    pub fn span(&self) -> Option<Range<usize>> {
+        use Error::*;
+        match self {
+            Empty => None,
+            Unexpected(i, c) => Some(*i..(*i + c.len_utf8())),
+            FractionalByte(span) | BadSuffix(span) => Some(span.clone()),
+            Overflow(span) | Negative(span) | BadRate(span) => Some(span.clone()),
+            BadWhole(span, _) | BadFractional(span, _) | BadExponent(span, _) => Some(span.clone()),
+        }
+    }
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.is_empty() { return Err(Error::Empty); }
-        let (mut dot, mut suffix) = (None, None);
-        for (i, c) in s.chars().enumerate() {
-            match c {
-                c if c.is_ascii_digit() && suffix.is_none() => continue,
-                '.' if dot.is_none() && suffix.is_none() => dot = Some(i),
-                c if is_suffix_char(c) && suffix.is_none() => suffix = Some(i),
-                c if is_suffix_char(c) => continue,
-                _ => Err(Error::Unexpected(i, c))?
+    /// Shifts all indices in `self` by `n`.
+    pub(crate) fn shift(self, n: usize) -> Error {
+        use Error::*;
+        let shift = |span: Range<usize>| (span.start + n)..(span.end + n);
+        match self {
+            Empty => Empty,
+            Unexpected(i, c) => Unexpected(i + n, c),
+            FractionalByte(span) => FractionalByte(shift(span)),
+            BadSuffix(span) => BadSuffix(shift(span)),
+            BadWhole(span, e) => BadWhole(shift(span), e),
+            Overflow(span) => Overflow(shift(span)),
+            Negative(span) => Negative(shift(span)),
+            BadFractional(span, e) => BadFractional(shift(span), e),
+            BadExponent(span, e) => BadExponent(shift(span), e),
+            BadRate(span) => BadRate(shift(span)),
+        }
+    }
+}
+
+/// Configuration of the grammar accepted by [`ByteUnit::parse_with()`].
+///
+/// The default options, [`ParseOptions::default()`] or equivalently
+/// [`ParseOptions::new()`], accept exactly the grammar accepted by the
+/// [`FromStr`](struct.ByteUnit.html#impl-FromStr) implementation. Builder
+/// methods extend or restrict the grammar.
+///
+/// | option                  | default | effect when enabled                       |
+/// |-------------------------|---------|-------------------------------------------|
+/// | [`allow_plus_sign`]     | `true`  | accept a leading `+`: `+5MB`              |
+/// | [`allow_underscores`]   | `true`  | accept `_` between digits: `1_000`        |
+/// | [`allow_exponents`]     | `true`  | accept exponents: `1e6 MB`                |
+/// | [`allow_radix`]         | `true`  | accept `0x`, `0o`, `0b` prefixes: `0x100` |
+/// | [`allow_long_names`]    | `true`  | accept unit names: `5 megabytes`          |
+/// | [`allow_grouping`]      | `false` | accept `,` separators: `1,024 KiB`        |
+/// | [`allow_shorthand`]     | `false` | accept `k`, `m`, ..., as `KiB`, `MiB`, ...|
+/// | [`default_binary`]      | `false` | read `kB`, `MB`, ..., as `KiB`, `MiB`, ...|
+/// | [`bit_aware`]           | `false` | read a lowercase `b` as bits: `1Mb`       |
+/// | [`saturate`]            | `false` | saturate when the whole part overflows    |
+/// | [`allow_suffix_first`]  | `false` | accept a suffix, then a count: `MiBx4`    |
+///
+/// [`allow_plus_sign`]: ParseOptions::allow_plus_sign()
+/// [`allow_underscores`]: ParseOptions::allow_underscores()
+/// [`allow_exponents`]: ParseOptions::allow_exponents()
+/// [`allow_radix`]: ParseOptions::allow_radix()
+/// [`allow_long_names`]: ParseOptions::allow_long_names()
+/// [`allow_grouping`]: ParseOptions::allow_grouping()
+/// [`allow_shorthand`]: ParseOptions::allow_shorthand()
+/// [`default_binary`]: ParseOptions::default_binary()
+/// [`bit_aware`]: ParseOptions::bit_aware()
+/// [`saturate`]: ParseOptions::saturate()
+/// [`allow_suffix_first`]: ParseOptions::allow_suffix_first()
+///
+/// # Example
+///
+/// ```rust
+/// use ubyte::{ByteUnit, ParseOptions, ToByteUnit};
+///
+/// const UNIX: ParseOptions = ParseOptions::new()
+///     .allow_shorthand(true)
+///     .default_binary(true)
+///     .saturate(true);
+///
+/// assert_eq!(ByteUnit::parse_with("512k", &UNIX).unwrap(), 512.kibibytes());
+/// assert_eq!(ByteUnit::parse_with("2 GB", &UNIX).unwrap(), 2.gibibytes());
+/// assert_eq!(ByteUnit::parse_with("99999999999999999999", &UNIX).unwrap(), ByteUnit::max_value());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ParseOptions {
+    plus_sign: bool,
+    underscores: bool,
+    exponents: bool,
+    radix: bool,
+    long_names: bool,
+    grouping: bool,
+    shorthand: bool,
+    default_binary: bool,
+    bit_aware: bool,
+    saturate: bool,
+    suffix_first: bool,
+}
+
+macro_rules! option_fns {
+    ($($(#[$attr:meta])* $name:ident => $field:ident),* $(,)?) => ($(
+        $(#[$attr])*
+        pub const fn $name(mut self, enable: bool) -> Self {
+            self.$field = enable;
+            self
+        }
+    )*)
+}
+
+impl ParseOptions {
+    /// The default options, accepting the same grammar as `FromStr`.
+    const DEFAULT: ParseOptions = ParseOptions {
+        plus_sign: true,
+        underscores: true,
+        exponents: true,
+        radix: true,
+        long_names: true,
+        grouping: false,
+        shorthand: false,
+        default_binary: false,
+        bit_aware: false,
+        saturate: false,
+        suffix_first: false,
+    };
+
+    /// Returns the default options, accepting the same grammar as the
+    /// [`FromStr`](struct.ByteUnit.html#impl-FromStr) implementation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteUnit, ParseOptions, ToByteUnit};
+    ///
+    /// let opts = ParseOptions::new();
+    /// assert_eq!(ByteUnit::parse_with("1.5 MiB", &opts).unwrap(), 1536.kibibytes());
+    /// assert_eq!(opts, ParseOptions::default());
+    /// ```
+    pub const fn new() -> ParseOptions {
+        ParseOptions::DEFAULT
+    }
+
+    option_fns! {
+        /// Sets whether a single leading `+`, as in `+5MB`, is accepted.
+        /// Enabled by default.
+        allow_plus_sign => plus_sign,
+
+        /// Sets whether `_` separators between digits, as in `1_000 kB`, are
+        /// accepted. Enabled by default.
+        allow_underscores => underscores,
+
+        /// Sets whether exponents, as in `1e6 MB`, are accepted. Enabled by
+        /// default.
+        allow_exponents => exponents,
+
+        /// Sets whether `0x`, `0o`, and `0b` prefixed whole numbers, as in
+        /// `0x1000`, are accepted. Enabled by default.
+        allow_radix => radix,
+
+        /// Sets whether singular and plural unit names, as in `5 megabytes`,
+        /// are accepted. Enabled by default.
+        allow_long_names => long_names,
+
+        /// Sets whether `,` thousands separators in the whole part, as in
+        /// `1,024 KiB`, are accepted. Disabled by default. See
+        /// [`ByteUnit::parse_grouped()`].
+        allow_grouping => grouping,
+
+        /// Sets whether the single-letter suffixes `k`, `m`, `g`, `t`, `p`, `e`,
+        /// `z`, and `y` are accepted as `KiB` through `YiB`.
+        /// Disabled by default. See [`ByteUnit::from_str_shorthand()`].
+        allow_shorthand => shorthand,
+
+        /// Sets whether decimal SI suffixes and names, as in `kB` or
+        /// `megabyte`, denote the corresponding binary unit, `KiB` or `MiB`.
+        /// Disabled by default.
+        default_binary => default_binary,
+
+        /// Sets whether a suffix ending in a lowercase `b`, as in `Mb` or
+        /// `kib`, denotes bits rather than bytes. A suffix ending in an
+        /// uppercase `B` continues to denote bytes. Disabled by default. See
+        /// [`ByteUnit::from_str_bit_aware()`].
+        bit_aware => bit_aware,
+
+        /// Sets whether a whole part exceeding `u64::MAX` saturates to
+        /// [`ByteUnit::max_value()`] instead of producing an error. Disabled
+        /// by default. See [`ByteUnit::from_str_lossy()`].
+        saturate => saturate,
+
+        /// Sets whether a suffix followed by `x` and a whole number count, as
+        /// in `MiBx4`, is accepted in addition to the usual number followed by
+        /// a suffix. The value is the count times the unit. Disabled by
+        /// default.
+        ///
+        /// ```rust
+        /// use ubyte::{ByteUnit, ParseOptions, ToByteUnit};
+        ///
+        /// let opts = ParseOptions::new().allow_suffix_first(true);
+        /// assert_eq!(ByteUnit::parse_with("MiBx4", &opts).unwrap(), 4.mebibytes());
+        /// assert_eq!(ByteUnit::parse_with("4MiB", &opts).unwrap(), 4.mebibytes());
+        /// assert_eq!(ByteUnit::parse_with("kilobytesX10", &opts).unwrap(), 10.kilobytes());
+        ///
+        /// assert!(ByteUnit::parse_with("MiBx1.5", &opts).is_err());
+        /// assert!(ByteUnit::parse_with("MiB", &opts).is_err());
+        /// assert!("MiBx4".parse::<ByteUnit>().is_err());
+        /// ```
+        allow_suffix_first => suffix_first,
+    }
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        ParseOptions::new()
+    }
+}
+
+impl ByteUnit {
+    /// Parses `s` as a `ByteUnit`. Equivalent to `s.parse::<ByteUnit>()`.
+    ///
+    /// See [`ByteUnit`#parsing](struct.ByteUnit.html#parsing) for the accepted
+    /// grammar. A value whose number fits in a `u64` but which exceeds
+    /// [`ByteUnit::max_value()`] once its unit is applied, like `20 EiB`,
+    /// saturates. A number that itself doesn't fit in a `u64` is rejected with
+    /// [`Error::Overflow`]; use [`ByteUnit::from_str_lossy()`] to saturate it
+    /// instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteUnit, Error, ToByteUnit};
+    ///
+    /// assert_eq!(ByteUnit::parse("512MiB").unwrap(), 512.mebibytes());
+    /// assert_eq!(ByteUnit::parse("1.5 kB").unwrap(), 1500.bytes());
+    /// assert_eq!(ByteUnit::parse("2 gigabytes").unwrap(), 2.gigabytes());
+    /// assert_eq!(ByteUnit::parse("0x400").unwrap(), 1.kibibytes());
+    /// assert_eq!(ByteUnit::parse("20 EiB").unwrap(), ByteUnit::max_value());
+    ///
+    /// let too_large = "18446744073709551616 B";
+    /// assert!(matches!(ByteUnit::parse(too_large), Err(Error::Overflow(_))));
+    /// assert_eq!(ByteUnit::from_str_lossy(too_large).unwrap(), ByteUnit::max_value());
+    ///
+    /// assert!(ByteUnit::parse("").is_err());
+    /// assert!(ByteUnit::parse("12 parsecs").is_err());
+    /// ```
+    pub fn parse(s: &str) -> Result<ByteUnit, Error> {
+        s.parse()
+    }
+
+    /// Parses `s` as a `ByteUnit` exactly like the
+    /// [`FromStr`](struct.ByteUnit.html#impl-FromStr) implementation but
+    /// additionally accepts `,` thousands separators in the whole part.
+    ///
+    /// Separators must split the whole part into groups of three digits, save
+    /// for the first group which may contain one to three digits. Separators
+    /// in any other position, including in the fractional part, are rejected.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteUnit, ToByteUnit};
+    ///
+    /// assert_eq!(ByteUnit::parse_grouped("1,024 KiB").unwrap(), 1.mebibytes());
+    /// assert_eq!(ByteUnit::parse_grouped("2,000,000 B").unwrap(), 2.megabytes());
+    /// assert_eq!(ByteUnit::parse_grouped("1,000.5 kB").unwrap(), 1000500.bytes());
+    /// assert_eq!(ByteUnit::parse_grouped("512 MiB").unwrap(), 512.mebibytes());
+    ///
+    /// assert!(ByteUnit::parse_grouped("1,00 MB").is_err());
+    /// assert!(ByteUnit::parse_grouped("1.000,5 MB").is_err());
+    /// assert!("1,024 KiB".parse::<ByteUnit>().is_err());
+    /// ```
+    pub fn parse_grouped(s: &str) -> Result<ByteUnit, Error> {
+        ByteUnit::parse_with(s, &ParseOptions::new().allow_grouping(true))
+    }
+
+    /// Parses `s` as a `ByteUnit` exactly like the
+    /// [`FromStr`](struct.ByteUnit.html#impl-FromStr) implementation but
+    /// additionally accepts a single-letter, case-insensitive suffix of `k`,
+    /// `m`, `g`, `t`, `p`, `e`, `z`, or `y` denoting the _binary_ units `KiB`,
+    /// `MiB`, `GiB`, `TiB`, `PiB`, `EiB`, `ZiB`, and `YiB`, respectively, as in
+    /// `dd` and `ls -h`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteUnit, ToByteUnit};
+    ///
+    /// assert_eq!(ByteUnit::from_str_shorthand("512k").unwrap(), 512.kibibytes());
+    /// assert_eq!(ByteUnit::from_str_shorthand("2g").unwrap(), 2.gibibytes());
+    /// assert_eq!(ByteUnit::from_str_shorthand("1.5 M").unwrap(), 1536.kibibytes());
+    /// assert_eq!(ByteUnit::from_str_shorthand("2kB").unwrap(), 2.kilobytes());
+    ///
+    /// assert!("512k".parse::<ByteUnit>().is_err());
+    /// ```
+    pub fn from_str_shorthand(s: &str) -> Result<ByteUnit, Error> {
+        ByteUnit::parse_with(s, &ParseOptions::new().allow_shorthand(true))
+    }
+
+    /// Parses `s` as a `ByteUnit` in a `const` context, panicking if `s` is
+    /// malformed. When used to initialize a `const` or `static`, a malformed
+    /// `s` is thus a compile-time error.
+    ///
+    /// Only a subset of the [`FromStr`](struct.ByteUnit.html#impl-FromStr)
+    /// grammar is accepted:
+    ///
+    /// ```ebnf
+    /// byte_unit := uint+ ('.' uint+)? WHITESPACE? suffix?
+    ///
+    /// uint := '0'..'9'
+    /// suffix := case insensitive SI byte unit suffix ('b' to 'yib')
+    /// WHITESPACE := ' '+ | the no-break space, U+00A0
+    /// ```
+    ///
+    /// As with all other `ByteUnit` operations, values saturate.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteUnit, ToByteUnit};
+    ///
+    /// const LIMIT: ByteUnit = ByteUnit::parse_const("512MiB");
+    /// const HALF_GB: ByteUnit = ByteUnit::parse_const("0.5 GB");
+    /// const PAGE: ByteUnit = ByteUnit::parse_const("4096");
+    ///
+    /// assert_eq!(LIMIT, 512.mebibytes());
+    /// assert_eq!(HALF_GB, 500.megabytes());
+    /// assert_eq!(PAGE, 4.kibibytes());
+    /// ```
+    ///
+    /// Malformed input fails to compile:
+    ///
+    /// ```rust,compile_fail
+    /// use ubyte::ByteUnit;
+    ///
+    /// const LIMIT: ByteUnit = ByteUnit::parse_const("512 MiX");
+    /// # let _ = LIMIT;
+    /// ```
+    pub const fn parse_const(s: &str) -> ByteUnit {
+        let bytes = s.as_bytes();
+        let mut i = 0;
+        let mut whole: u64 = 0;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            whole = whole.saturating_mul(10).saturating_add((bytes[i] - b'0') as u64);
+            i += 1;
+        }
+
+        if i == 0 {
+            panic!("byte unit must begin with a digit");
+        }
+
+        let frac_start = i + 1;
+        let has_frac = i < bytes.len() && bytes[i] == b'.';
+        if has_frac {
+            i += 1;
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+
+            if i == frac_start {
+                panic!("fractional part of byte unit must be non-empty");
+            }
+        }
+
+        let num_end = i;
+        i = skip_space(bytes, i);
+
+        let unit = match (i == bytes.len(), i == num_end) {
+            (true, true) => 1,
+            (true, false) => panic!("byte unit must not end with whitespace"),
+            (false, _) => {
+                let (_, suffix) = bytes.split_at(i);
+                let mut j = 0;
+                loop {
+                    if j == SUFFIXES.len() {
+                        panic!("unknown or malformed byte unit suffix");
+                    }
+
+                    let (string, unit) = SUFFIXES[j];
+                    if eq_ignore_ascii_case(suffix, string.as_bytes()) {
+                        break unit;
+                    }
+
+                    j += 1;
+                }
             }
+        };
+
+        if has_frac && unit == 1 {
+            panic!("unit `B` cannot have a fractional component");
         }
 
-        // We can't start with `.` or a suffix character.
-        if dot.map(|i| i == 0).unwrap_or(false) || suffix.map(|i| i == 0).unwrap_or(false) {
-            return Err(Error::Unexpected(0, s.as_bytes()[0] as char));
+        // Accumulate the fraction exactly as `frac_of_unit()` does.
+        let (mut frac, mut j) = (0u128, num_end);
+        while has_frac && j > frac_start {
+            j -= 1;
+            frac = (frac + (bytes[j] - b'0') as u128 * unit) / 10;
         }
 
-        // Parse the suffix. A fractional doesn't make sense for bytes.
-        let suffix_str = suffix.map(|i| s[i..].trim_start()).unwrap_or("b");
-        let unit = parse_suffix(suffix_str).ok_or(Error::BadSuffix)?;
-        if unit == ByteUnit::B && dot.is_some() {
-            return Err(Error::FractionalByte);
+        saturate((whole as u128).saturating_mul(unit).saturating_add(frac))
+    }
+
+    /// Parses `s` as a `ByteUnit` exactly like the
+    /// [`FromStr`](struct.ByteUnit.html#impl-FromStr) implementation except
+    /// that a whole part too large to fit in a `u64` saturates to
+    /// [`ByteUnit::max_value()`] instead of producing an error.
+    ///
+    /// All other errors, including syntactic errors, are returned as usual.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteUnit, ToByteUnit};
+    ///
+    /// let max = ByteUnit::max_value();
+    /// assert_eq!(ByteUnit::from_str_lossy("287423890740938348498349344").unwrap(), max);
+    /// assert_eq!(ByteUnit::from_str_lossy("287423890740938348498349344 KiB").unwrap(), max);
+    /// assert_eq!(ByteUnit::from_str_lossy("512 KiB").unwrap(), 512.kibibytes());
+    ///
+    /// assert!(ByteUnit::from_str_lossy("287423890740938348498349344 KiX").is_err());
+    /// assert!("287423890740938348498349344".parse::<ByteUnit>().is_err());
+    /// ```
+    pub fn from_str_lossy(s: &str) -> Result<ByteUnit, Error> {
+        ByteUnit::parse_with(s, &ParseOptions::new().saturate(true))
+    }
+
+    /// Parses `s` as a `ByteUnit` exactly like the
+    /// [`FromStr`](struct.ByteUnit.html#impl-FromStr) implementation except
+    /// that a suffix ending in a lowercase `b` denotes _bits_ while a suffix
+    /// ending in an uppercase `B` denotes bytes.
+    ///
+    /// Values in bits are converted to bytes by dividing by `8`, rounding
+    /// toward zero. Since every bit unit other than the bit itself is a whole
+    /// number of bytes, only values in plain bits, like `"12b"`, are rounded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteUnit, ToByteUnit};
+    ///
+    /// assert_eq!(ByteUnit::from_str_bit_aware("1MB").unwrap(), 1.megabytes());
+    /// assert_eq!(ByteUnit::from_str_bit_aware("1Mb").unwrap(), 125.kilobytes());
+    /// assert_eq!(ByteUnit::from_str_bit_aware("8 Kib").unwrap(), 1.kibibytes());
+    /// assert_eq!(ByteUnit::from_str_bit_aware("1.5 kb").unwrap(), 187.bytes());
+    /// assert_eq!(ByteUnit::from_str_bit_aware("12b").unwrap(), 1.bytes());
+    /// assert_eq!(ByteUnit::from_str_bit_aware("12B").unwrap(), 12.bytes());
+    ///
+    /// assert_eq!("1Mb".parse::<ByteUnit>().unwrap(), 1.megabytes());
+    /// ```
+    pub fn from_str_bit_aware(s: &str) -> Result<ByteUnit, Error> {
+        ByteUnit::parse_with(s, &ParseOptions::new().bit_aware(true))
+    }
+
+    /// Parses `s` as a `ByteUnit` using the grammar configured by `opts`.
+    ///
+    /// With [`ParseOptions::default()`], this is identical to the
+    /// [`FromStr`](struct.ByteUnit.html#impl-FromStr) implementation. See
+    /// [`ParseOptions`] for the available extensions and restrictions.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteUnit, ParseOptions, ToByteUnit};
+    ///
+    /// let opts = ParseOptions::new().allow_shorthand(true).default_binary(true);
+    /// assert_eq!(ByteUnit::parse_with("4k", &opts).unwrap(), 4.kibibytes());
+    /// assert_eq!(ByteUnit::parse_with("4 KB", &opts).unwrap(), 4.kibibytes());
+    ///
+    /// let strict = ParseOptions::new().allow_long_names(false).allow_exponents(false);
+    /// assert!(ByteUnit::parse_with("4 kilobytes", &strict).is_err());
+    /// assert!(ByteUnit::parse_with("4e3 kB", &strict).is_err());
+    /// assert_eq!(ByteUnit::parse_with("4 kB", &strict).unwrap(), 4.kilobytes());
+    /// ```
+    pub fn parse_with(s: &str, opts: &ParseOptions) -> Result<ByteUnit, Error> {
+        match parse(s, opts) {
+            Err(Error::Overflow(_)) if opts.saturate => Ok(ByteUnit::max_value()),
+            result => result,
         }
+    }
+
+    /// Parses `s` as a sum of whitespace-separated byte units, each using the
+    /// same grammar as the [`FromStr`](struct.ByteUnit.html#impl-FromStr)
+    /// implementation, returning their saturating sum.
+    ///
+    /// A component ends at a valid suffix followed by whitespace and a digit,
+    /// which begins the next component. When there is more than one
+    /// component, every component must have a suffix. A single component is
+    /// parsed exactly as `FromStr` would parse it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteUnit, ToByteUnit};
+    ///
+    /// let total = ByteUnit::parse_sum("1GiB 512MiB 10KiB").unwrap();
+    /// assert_eq!(total, 1.gibibytes() + 512.mebibytes() + 10.kibibytes());
+    ///
+    /// let total = ByteUnit::parse_sum("1 GiB 512 MiB").unwrap();
+    /// assert_eq!(total, 1.gibibytes() + 512.mebibytes());
+    ///
+    /// assert_eq!(ByteUnit::parse_sum("1.5 MB").unwrap(), 1500.kilobytes());
+    /// assert_eq!(ByteUnit::parse_sum("1024").unwrap(), 1.kibibytes());
+    /// assert_eq!(ByteUnit::parse_sum("16EiB 1EiB").unwrap(), ByteUnit::max_value());
+    ///
+    /// assert!(ByteUnit::parse_sum("1GiB 512").is_err());
+    /// assert!(ByteUnit::parse_sum("512 1GiB").is_err());
+    /// assert!(ByteUnit::parse_sum("0xAB 1MB").is_err());
+    /// ```
+    pub fn parse_sum(s: &str) -> Result<ByteUnit, Error> {
+        let (mut total, mut start) = (ByteUnit(0), 0);
+        loop {
+            // The component ends after its suffix if whitespace and a digit follow.
+            let rest = &s[start..];
+            let (value, len, has_suffix) = scan(rest, &ParseOptions::DEFAULT, true)
+                .map_err(|e| e.shift(start))?;
 
-        let num_end = suffix.unwrap_or(s.len());
-        match dot {
-            Some(i) => {
-                let frac_str = &s[(i + 1)..num_end];
-                let whole: u64 = s[..i].parse().map_err(Error::BadWhole)?;
-                let frac: u32 = frac_str.parse().map_err(Error::BadFractional)?;
-                let frac_part = frac as f64 / 10u64.saturating_pow(frac_str.len() as u32) as f64;
-                let frac_unit = (frac_part * unit.as_u64() as f64) as u64;
-                Ok(whole * unit + frac_unit)
+            let (end, next) = (start + len, skip_space(s.as_bytes(), start + len));
+            let splits = has_suffix && next > end
+                && s[next..].starts_with(|c: char| c.is_ascii_digit());
+
+            if !splits {
+                let value = parse(rest, &ParseOptions::DEFAULT).map_err(|e| e.shift(start))?;
+                if start > 0 && !has_suffix {
+                    return Err(Error::BadSuffix(s.len()..s.len()));
+                }
+
+                return Ok(total + value);
             }
-            None => {
-                let whole: u64 = s[..num_end].parse().map_err(Error::BadWhole)?;
-                Ok(whole * unit)
+
+            total += value;
+            start = next;
+        }
+    }
+
+    /// Parses the longest prefix of `s` that forms a byte unit, returning the
+    /// parsed value and the unconsumed remainder of `s`.
+    ///
+    /// The prefix is parsed exactly as the
+    /// [`FromStr`](struct.ByteUnit.html#impl-FromStr) implementation would
+    /// parse it. Whitespace following the number is only consumed if it is
+    /// followed by a suffix; otherwise, it is left in the remainder.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteUnit, ToByteUnit};
+    ///
+    /// let (value, rest) = ByteUnit::parse_prefix("512MiB then more").unwrap();
+    /// assert_eq!(value, 512.mebibytes());
+    /// assert_eq!(rest, " then more");
+    ///
+    /// let (value, rest) = ByteUnit::parse_prefix("512 MiB, 1 GiB").unwrap();
+    /// assert_eq!(value, 512.mebibytes());
+    /// assert_eq!(rest, ", 1 GiB");
+    ///
+    /// let (value, rest) = ByteUnit::parse_prefix("512 then").unwrap();
+    /// assert_eq!(value, 512.bytes());
+    /// assert_eq!(rest, " then");
+    ///
+    /// let (value, rest) = ByteUnit::parse_prefix("1.5kbps").unwrap();
+    /// assert_eq!(value, 1500.bytes());
+    /// assert_eq!(rest, "ps");
+    ///
+    /// assert!(ByteUnit::parse_prefix("then 512").is_err());
+    /// ```
+    pub fn parse_prefix(s: &str) -> Result<(ByteUnit, &str), Error> {
+        let (value, len, _) = scan(s, &ParseOptions::DEFAULT, true)?;
+        Ok((value, &s[len..]))
+    }
+}
+
+impl core::str::FromStr for ByteUnit {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ByteUnit::parse_with(s, &ParseOptions::DEFAULT)
+    }
+}
+
+/// Parses a `ByteUnit` exactly as the
+/// [`FromStr`](struct.ByteUnit.html#impl-FromStr) implementation does.
+///
+/// # Example
+///
+/// ```rust
+/// use core::convert::TryFrom;
+/// use ubyte::{ByteUnit, ToByteUnit};
+///
+/// assert_eq!(ByteUnit::try_from("512 MiB").unwrap(), 512.mebibytes());
+/// assert!(ByteUnit::try_from("512 parsecs").is_err());
+/// ```
+impl TryFrom<&str> for ByteUnit {
+    type Error = Error;
+
+    #[inline(always)]
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+/// Parses a `ByteUnit` exactly as the
+/// [`FromStr`](struct.ByteUnit.html#impl-FromStr) implementation does.
+/// Requires the `alloc` feature.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "alloc")] {
+/// use core::convert::TryFrom;
+/// use ubyte::{ByteUnit, ToByteUnit};
+///
+/// assert_eq!(ByteUnit::try_from(String::from("2kB")).unwrap(), 2.kilobytes());
+/// # }
+/// ```
+#[cfg(feature = "alloc")]
+impl TryFrom<alloc::string::String> for ByteUnit {
+    type Error = Error;
+
+    #[inline(always)]
+    fn try_from(s: alloc::string::String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+/// Parses `s` using the grammar configured by `opts`, without saturating.
+fn parse(s: &str, opts: &ParseOptions) -> Result<ByteUnit, Error> {
+    scan(s, opts, false).map(|(value, ..)| value)
+}
+
+/// Scans `s` using the grammar configured by `opts`, without saturating,
+/// returning the value, the length of `s` it spans, and whether it has a
+/// suffix.
+///
+/// Unless `partial`, the value must span all of `s`, and the first character
+/// that doesn't fit the grammar is an error. With `partial`, the scan instead
+/// ends at that character, spanning the longest prefix of `s` that forms a
+/// byte unit. This is the only scanner: `FromStr`, `parse_prefix()`, and
+/// `parse_sum()` all use it and thus agree on the grammar.
+fn scan(s: &str, opts: &ParseOptions, partial: bool) -> Result<(ByteUnit, usize, bool), Error> {
+    if s.is_empty() { return Err(Error::Empty); }
+
+    // Byte units can't be negative. Say so instead of pointing at the `-`.
+    let trimmed = s.trim_start();
+    if trimmed.len() > 1 && trimmed.starts_with('-') {
+        let i = s.len() - trimmed.len();
+        return Err(Error::Negative(i..(i + 1)));
+    }
+
+    // A single leading `+` is permitted and has no effect on the value.
+    if let Some(rest) = s.strip_prefix('+').filter(|_| opts.plus_sign) {
+        match rest.chars().next() {
+            None => return Err(Error::Unexpected(0, '+')),
+            Some(c@'+') | Some(c@'-') => return Err(Error::Unexpected(1, c)),
+            Some(_) => {}
+        }
+
+        let (value, len, has_suffix) = scan(rest, opts, partial).map_err(|e| e.shift(1))?;
+        return Ok((value, len + 1, has_suffix));
+    }
+
+    if let Some(radix) = radix_prefix(s).filter(|_| opts.radix) {
+        return scan_radix(s, radix, opts, partial);
+    }
+
+    if opts.suffix_first && s.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        return parse_suffix_first(s, opts).map(|value| (value, s.len(), true));
+    }
+
+    let (mut dot, mut exp, mut suffix, mut end) = (None, None, None, s.len());
+    for (i, c) in s.char_indices() {
+        match c {
+            c if c.is_ascii_digit() && suffix.is_none() => continue,
+            '.' if dot.is_none() && exp.is_none() && suffix.is_none()
+                && (!partial || s[(i + 1)..].starts_with(|c: char| c.is_ascii_digit()))
+                => dot = Some(i),
+            'e' | 'E' if opts.exponents && exp.is_none() && suffix.is_none()
+                && is_exponent_start(&s[(i + 1)..], opts.shorthand, partial) => exp = Some(i),
+            '+' | '-' if exp.map(|e| e + 1 == i).unwrap_or(false) => continue,
+            '_' if opts.underscores && exp.is_none() && suffix.is_none()
+                && is_digit_separator(s, i) => continue,
+            ',' if opts.grouping && dot.is_none() && exp.is_none() && suffix.is_none()
+                && is_group_separator(s, i) => continue,
+            c if is_suffix_char(c) && suffix.is_none() => suffix = Some(i),
+            c if suffix.is_some() && is_suffix_continue_char(c) => continue,
+            _ if partial => { end = i; break; }
+            _ => Err(Error::Unexpected(i, c))?
+        }
+    }
+
+    // Without a number to end after, report the error for all of `s`.
+    if partial && (end == 0 || [dot, exp, suffix].contains(&Some(0))) {
+        return scan(s, opts, false);
+    }
+
+    // We can't start with `.`, an exponent, or a suffix character.
+    if let Some(c) = s.chars().next().filter(|_| [dot, exp, suffix].contains(&Some(0))) {
+        return Err(Error::Unexpected(0, c));
+    }
+
+    // A partial scan ends after the longest valid suffix or else the number.
+    let mut s = &s[..end];
+    if let Some(i) = suffix.filter(|_| partial) {
+        let k = skip_space(s.as_bytes(), i);
+        let len = suffix_len(&s[k..], opts);
+        s = &s[..len.map_or(i, |n| k + n)];
+        suffix = suffix.filter(|_| len.is_some());
+    }
+
+    // Parse the suffix. A fractional doesn't make sense for bytes.
+    let num_end = suffix.unwrap_or(s.len());
+    let suffix_start = suffix.map(|i| skip_space(s.as_bytes(), i)).unwrap_or(s.len());
+    let suffix_str = suffix.map(|_| &s[suffix_start..]).unwrap_or("b");
+    let unit = parse_suffix(suffix_str, opts).ok_or(Error::BadSuffix(suffix_start..s.len()))?;
+    let (unit, divisor) = bits_to_bytes(unit, suffix.map(|_| suffix_str), opts);
+
+    let frac_span = dot.map(|i| i..exp.unwrap_or(num_end));
+    if let Some(span) = frac_span.clone().filter(|_| unit == 1 && exp.is_none()) {
+        return Err(Error::FractionalByte(span));
+    }
+
+    let whole_span = 0..dot.or(exp).unwrap_or(num_end);
+    let whole: u64 = parse_int(&s[whole_span.clone()]).map_err(|e| match e.kind() {
+        IntErrorKind::PosOverflow => Error::Overflow(whole_span.clone()),
+        _ => Error::BadWhole(whole_span.clone(), e),
+    })?;
+    let frac = match frac_span {
+        Some(span) => {
+            // Only digits and separators remain, so the only possible error is
+            // an empty fractional part. Report it as `parse()` would.
+            let frac_str = &s[(span.start + 1)..span.end];
+            if frac_str.is_empty() {
+                let e = frac_str.parse::<u8>().unwrap_err();
+                return Err(Error::BadFractional(span, e));
             }
+
+            Some(frac_str)
+        }
+        None => None,
+    };
+
+    if let Some(e) = exp {
+        // An exponent beyond an `i32` saturates the value to max or zero.
+        let exponent = match s[(e + 1)..num_end].parse::<i32>() {
+            Ok(exponent) => exponent,
+            Err(err) => match err.kind() {
+                IntErrorKind::PosOverflow => i32::MAX,
+                IntErrorKind::NegOverflow => i32::MIN,
+                _ => return Err(Error::BadExponent(e..num_end, err)),
+            },
+        };
+
+        let whole_str = &s[whole_span];
+        let (value, has_frac) = shift_of_unit(whole_str, frac.unwrap_or(""), exponent, unit);
+        if unit == 1 && has_frac {
+            return Err(Error::FractionalByte(0..num_end));
+        }
+
+        return Ok((saturate(value) / divisor, s.len(), suffix.is_some()));
+    }
+
+    let frac_unit = frac.map(|digits| frac_of_unit(digits, unit)).unwrap_or(0);
+    let value = (whole as u128).saturating_mul(unit).saturating_add(frac_unit);
+    Ok((saturate(value) / divisor, s.len(), suffix.is_some()))
+}
+
+/// Scans `s`, which begins with a radix prefix for `radix`, as a whole number
+/// of units as [`scan()`] does. Fractional components and exponents are not
+/// permitted.
+fn scan_radix(
+    s: &str,
+    radix: u32,
+    opts: &ParseOptions,
+    partial: bool,
+) -> Result<(ByteUnit, usize, bool), Error> {
+    let num_end = s[2..].find(|c: char| !c.is_digit(radix)).map(|i| i + 2).unwrap_or(s.len());
+    let whole = u64::from_str_radix(&s[2..num_end], radix).map_err(|e| match e.kind() {
+        IntErrorKind::PosOverflow => Error::Overflow(0..num_end),
+        _ => Error::BadWhole(0..num_end, e),
+    })?;
+
+    let mut end = s.len();
+    for (i, c) in s[num_end..].char_indices() {
+        match c {
+            c if i == 0 && is_suffix_char(c) => continue,
+            c if i > 0 && is_suffix_continue_char(c) => continue,
+            _ if partial => { end = num_end + i; break; }
+            _ => return Err(Error::Unexpected(num_end + i, c)),
         }
     }
+
+    // A partial scan ends after the longest valid suffix or else the number.
+    let mut s = &s[..end];
+    if partial {
+        let k = skip_space(s.as_bytes(), num_end);
+        s = &s[..suffix_len(&s[k..], opts).map_or(num_end, |n| k + n)];
+    }
+
+    let suffix_start = skip_space(s.as_bytes(), num_end);
+    let suffix = Some(&s[suffix_start..]).filter(|_| num_end < s.len());
+    let unit = match suffix {
+        Some(suffix) => parse_suffix(suffix, opts).ok_or(Error::BadSuffix(suffix_start..s.len()))?,
+        None => 1,
+    };
+
+    let (unit, divisor) = bits_to_bytes(unit, suffix, opts);
+    Ok((saturate((whole as u128).saturating_mul(unit)) / divisor, s.len(), suffix.is_some()))
+}
+
+/// Parses `s`, which begins with a suffix, as the suffix followed by `x` and a
+/// whole number count of units, as in `MiBx4`.
+fn parse_suffix_first(s: &str, opts: &ParseOptions) -> Result<ByteUnit, Error> {
+    let x = s.rfind(['x', 'X']).unwrap_or(s.len());
+    let suffix = &s[..x];
+    let unit = parse_suffix(suffix, opts).ok_or(Error::BadSuffix(0..x))?;
+    let (unit, divisor) = bits_to_bytes(unit, Some(suffix), opts);
+
+    let count_span = (x + 1).min(s.len())..s.len();
+    let count = &s[count_span.clone()];
+    if let Some((i, c)) = count.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+        return Err(Error::Unexpected(count_span.start + i, c));
+    }
+
+    let count: u64 = count.parse().map_err(|e: ParseIntError| match e.kind() {
+        IntErrorKind::PosOverflow => Error::Overflow(count_span.clone()),
+        _ => Error::BadWhole(count_span.clone(), e),
+    })?;
+
+    Ok(saturate((count as u128).saturating_mul(unit)) / divisor)
 }
 
 impl core::fmt::Display for Error {
@@ -92,10 +1169,52 @@ impl core::fmt::Display for Error {
         match self {
             Empty => write!(f, "the input was empty"),
             Unexpected(i, c) => write!(f, "unexpected character {:?} at index `{}`", c, i),
-            FractionalByte => write!(f, "unit `B` cannot have a fractional component"),
-            BadSuffix => write!(f, "unknown or malformed byte unit suffix"),
-            BadWhole(e) => write!(f, "whole part failed to parse: {}", e),
-            BadFractional(e) => write!(f, "fractional part failed to parse: {}", e),
+            FractionalByte(span) => write!(f,
+                "unit `B` cannot have a fractional component at index `{}`", span.start),
+            BadSuffix(span) => write!(f,
+                "unknown or malformed byte unit suffix at index `{}`", span.start),
+            BadWhole(span, e) => write!(f,
+                "whole part at index `{}` failed to parse: {}", span.start, e),
+            Overflow(span) => write!(f,
+                "whole part at index `{}` exceeds the maximum of `{}`", span.start, u64::MAX),
+            Negative(span) => write!(f,
+                "byte units cannot be negative: found `-` at index `{}`", span.start),
+            BadFractional(span, e) => write!(f,
+                "fractional part at index `{}` failed to parse: {}", span.start, e),
+            BadExponent(span, e) => write!(f,
+                "exponent at index `{}` failed to parse: {}", span.start, e),
+            BadRate(span) => write!(f,
+                "missing or unknown rate time unit at index `{}`", span.start),
+        }
+    }
+}
+
+/// The [`source()`](core::error::Error::source) of a `BadWhole`,
+/// `BadFractional`, or `BadExponent` error is the underlying `ParseIntError`.
+///
+/// # Example
+///
+/// ```rust
+/// use core::error::Error as _;
+/// use ubyte::{ByteUnit, Error};
+///
+/// let error = "5.kb".parse::<ByteUnit>().unwrap_err();
+/// assert!(matches!(error, Error::BadFractional(..)));
+/// assert!(error.source().is_some());
+///
+/// let error = "1 parsec".parse::<ByteUnit>().unwrap_err();
+/// assert!(error.source().is_none());
+///
+/// let error: Box<dyn std::error::Error> = Box::new(error);
+/// assert!(error.to_string().contains("suffix"));
+/// ```
+impl core::error::Error for Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Error::BadWhole(_, e) => Some(e),
+            Error::BadFractional(_, e) => Some(e),
+            Error::BadExponent(_, e) => Some(e),
+            _ => None,
         }
     }
 }
@@ -128,6 +1247,697 @@ mod parse_tests {
         assert_reject!["1.2mkb", "1kb2", "1MB ", " 1MB"];
         assert_reject!["287423890740938348498349344"];
         assert_reject!["1.kb", "1.", "1. ", "2. kb"];
+        assert_reject!["1e", "1e+", "1e-", "1e MB", "1e+kb", "e5", "1e5.2", "1e--3"];
+        assert_reject!["1.5e", "1e2e3", "1e-1", "1.25e1", "1e+-2kb"];
+        assert_reject!["1 mega", "1mega", "1 megabytess", "1 mega byte", "1 megabites"];
+        assert_reject!["1 bytes s", "1.5 byte", "1.5bytes", "1 kilo", "1 s", "1 kilobyte "];
+        assert_reject!["1 kb s"];
+        assert_reject!["18_446_744_073_709_551_616", "1_000_000_000_000_000_000_000"];
+        assert_reject!["_", "__", "1_"];
+    }
+
+    #[test]
+    fn bad_separator() {
+        use crate::Error;
+
+        let cases = [("_100", 0), ("100_", 3), ("1__0", 1), ("1_.5kb", 1), ("1._5kb", 2),
+            ("1.5_kb", 3), ("1_kb", 1), ("1 _kb", 2), ("1e1_0", 3), ("1_000 k_b", 7)];
+
+        for &(s, index) in &cases {
+            match ByteUnit::from_str(s) {
+                Err(Error::Unexpected(i, '_')) if i == index => continue,
+                result => panic!("{:?} parsed as {:?}", s, result),
+            }
+        }
+    }
+
+    #[test]
+    fn grouped() {
+        use crate::Error;
+
+        assert_eq!(ByteUnit::parse_grouped("1,024 KiB").unwrap(), 1.mebibytes());
+        assert_eq!(ByteUnit::parse_grouped("2,000,000 B").unwrap(), 2.megabytes());
+        assert_eq!(ByteUnit::parse_grouped("999,999").unwrap(), 999999.bytes());
+        assert_eq!(ByteUnit::parse_grouped("1,000kb").unwrap(), 1.megabytes());
+        assert_eq!(ByteUnit::parse_grouped("1,000.25MB").unwrap(), 1000250.kilobytes());
+        assert_eq!(ByteUnit::parse_grouped("1,000e3").unwrap(), 1.megabytes());
+        assert_eq!(ByteUnit::parse_grouped("1_000").unwrap(), 1.kilobytes());
+        assert_eq!(ByteUnit::parse_grouped("10").unwrap(), 10.bytes());
+
+        let cases = [("1,00", 1), ("1,0000", 1), (",100", 0), ("100,", 3), ("1000,000", 4),
+            ("1,000,00", 5), ("1,,000", 1), ("1.000,5kb", 5), ("1_000,000", 5),
+            ("1,000_000", 1), ("1,000e1,000", 7), ("1,000 k,b", 7)];
+
+        for &(s, index) in &cases {
+            match ByteUnit::parse_grouped(s) {
+                Err(Error::Unexpected(i, ',')) if i == index => continue,
+                result => panic!("{:?} parsed as {:?}", s, result),
+            }
+        }
+
+        assert_reject!["1,024 KiB", "2,000,000 B", "1,000"];
+    }
+
+    #[test]
+    fn plus_sign() {
+        use crate::Error;
+
+        let cases = [("+", 0, '+'), ("++5", 1, '+'), ("5+", 1, '+'), ("+5+kb", 2, '+'),
+            ("+ 5", 2, '5'), ("5 +kb", 2, '+'), ("+.5kb", 1, '.'), ("+_5", 1, '_')];
+
+        for &(s, index, chr) in &cases {
+            match ByteUnit::from_str(s) {
+                Err(Error::Unexpected(i, c)) if i == index && c == chr => continue,
+                result => panic!("{:?} parsed as {:?}", s, result),
+            }
+        }
+
+        assert_reject!["+-5", "-+5", "+1.5b"];
+    }
+
+    #[test]
+    fn lossy() {
+        use crate::Error;
+
+        let max = ByteUnit::max_value();
+        assert_eq!(ByteUnit::from_str_lossy("287423890740938348498349344").unwrap(), max);
+        assert_eq!(ByteUnit::from_str_lossy("18446744073709551616").unwrap(), max);
+        assert_eq!(ByteUnit::from_str_lossy("18446744073709551615").unwrap(), max);
+        assert_eq!(ByteUnit::from_str_lossy("99999999999999999999 kb").unwrap(), max);
+        assert_eq!(ByteUnit::from_str_lossy("99999999999999999999.5 kb").unwrap(), max);
+        assert_eq!(ByteUnit::from_str_lossy("99_999_999_999_999_999_999").unwrap(), max);
+        assert_eq!(ByteUnit::from_str_lossy("+99999999999999999999").unwrap(), max);
+        assert_eq!(ByteUnit::from_str_lossy("1.5 MB").unwrap(), 1500.kilobytes());
+
+        macro_rules! assert_lossy_err {
+            ($($s:expr => $pat:pat),* $(,)?) => ($(
+                let result = ByteUnit::from_str_lossy($s);
+                assert!(matches!(result, Err($pat)), "{:?} parsed as {:?}", $s, result);
+            )*)
+        }
+
+        assert_lossy_err! {
+            "" => Error::Empty,
+            "99999999999999999999 kx" => Error::BadSuffix(_),
+            "99999999999999999999?" => Error::Unexpected(..),
+            "99999999999999999999.5" => Error::FractionalByte(_),
+            "1. kb" => Error::BadFractional(..),
+            "1e kb" => Error::BadSuffix(_),
+        }
+    }
+
+    #[test]
+    fn spans() {
+        use crate::Error;
+
+        macro_rules! assert_span {
+            ($($s:expr => $pat:pat, $span:expr),* $(,)?) => ($(
+                let error = ByteUnit::from_str($s).unwrap_err();
+                assert!(matches!(error, $pat), "{:?} failed with {:?}", $s, error);
+                assert_eq!(error.span(), $span, "{:?} failed with {:?}", $s, error);
+            )*)
+        }
+
+        assert_span! {
+            "" => Error::Empty, None,
+            "1?" => Error::Unexpected(1, '?'), Some(1..2),
+            "1 kx" => Error::BadSuffix(_), Some(2..4),
+            "1   kx" => Error::BadSuffix(_), Some(4..6),
+            "1kx" => Error::BadSuffix(_), Some(1..3),
+            "+1kx" => Error::BadSuffix(_), Some(2..4),
+            "1.5" => Error::FractionalByte(_), Some(1..3),
+            "1.5 b" => Error::FractionalByte(_), Some(1..3),
+            "+1.5 b" => Error::FractionalByte(_), Some(2..4),
+            "1.5e-1" => Error::FractionalByte(_), Some(0..6),
+            "1.5e-1 B" => Error::FractionalByte(_), Some(0..6),
+            "2.35e1" => Error::FractionalByte(_), Some(0..6),
+            "1e-400" => Error::FractionalByte(_), Some(0..6),
+            "1e-99999999999" => Error::FractionalByte(_), Some(0..14),
+            "99999999999999999999kb" => Error::Overflow(_), Some(0..20),
+            "99999999999999999999.5kb" => Error::Overflow(_), Some(0..20),
+            "+99_999_999_999_999_999_999" => Error::Overflow(_), Some(1..27),
+            "18446744073709551616" => Error::Overflow(_), Some(0..20),
+            "99999999999999999999e-30" => Error::Overflow(_), Some(0..20),
+            "-5MB" => Error::Negative(_), Some(0..1),
+            "-5" => Error::Negative(_), Some(0..1),
+            "-0" => Error::Negative(_), Some(0..1),
+            "-x" => Error::Negative(_), Some(0..1),
+            "  -5 MB" => Error::Negative(_), Some(2..3),
+            "-" => Error::Unexpected(0, '-'), Some(0..1),
+            " -" => Error::Unexpected(1, '-'), Some(1..2),
+            "5-MB" => Error::Unexpected(1, '-'), Some(1..2),
+            "+-5MB" => Error::Unexpected(1, '-'), Some(1..2),
+            "1.kb" => Error::BadFractional(..), Some(1..2),
+            "1e+kb" => Error::BadExponent(..), Some(1..3),
+            "+1.5e+kb" => Error::BadExponent(..), Some(4..6),
+        }
+
+        let error = ByteUnit::parse_grouped("+1,000.kb").unwrap_err();
+        assert_eq!(error.span(), Some(6..7));
+    }
+
+    #[test]
+    fn sum() {
+        use crate::Error;
+
+        macro_rules! assert_sum {
+            ($($s:expr => $b:expr),* $(,)?) => ($(
+                let result = ByteUnit::parse_sum($s);
+                assert!(result.is_ok(), "{:?} failed to parse: {}", $s, result.unwrap_err());
+                assert_eq!(result.unwrap(), $b, "{:?} parsed incorrectly", $s);
+            )*)
+        }
+
+        assert_sum! {
+            "1GiB 512MiB 10KiB" => 1.gibibytes() + 512.mebibytes() + 10.kibibytes(),
+            "1GiB 512MiB" => 1.gibibytes() + 512.mebibytes(),
+            "1 GiB 512 MiB" => 1.gibibytes() + 512.mebibytes(),
+            "1GiB  512MiB" => 1.gibibytes() + 512.mebibytes(),
+            "1.5GB 1 byte" => 1500.megabytes() + 1,
+            "1e3 kB 1 MB" => 2.megabytes(),
+            "1 gigabyte 2 megabytes" => 1.gigabytes() + 2.megabytes(),
+            "+1GiB 1GiB" => 2.gibibytes(),
+            "10EiB 10EiB" => ByteUnit::max_value(),
+            "1b" => 1.bytes(),
+            "1" => 1.bytes(),
+            "1.5 KiB" => 1536.bytes(),
+            "0x10 B 1KiB" => 1040.bytes(),
+        }
+
+        let cases = [
+            ("", Error::Empty, None),
+            ("1GiB 512", Error::BadSuffix(8..8), Some(8..8)),
+            ("1 GiB 512  ", Error::BadSuffix(11..11), Some(11..11)),
+            ("1GiB 2e3", Error::BadSuffix(8..8), Some(8..8)),
+            ("1GiB ", Error::BadSuffix(1..5), Some(1..5)),
+            ("512 1GiB", Error::Unexpected(4, '1'), Some(4..5)),
+            ("1GiB 1.5b", Error::FractionalByte(6..8), Some(6..8)),
+            ("1GiB 1kx", Error::BadSuffix(6..8), Some(6..8)),
+            ("1GiB 1KiB?", Error::Unexpected(9, '?'), Some(9..10)),
+            ("1GiB +1KiB", Error::Unexpected(5, '+'), Some(5..6)),
+            ("0xAB 1MB", Error::Unexpected(5, '1'), Some(5..6)),
+            ("0x1B 1MB", Error::Unexpected(5, '1'), Some(5..6)),
+            ("1 kx 1MB", Error::Unexpected(5, '1'), Some(5..6)),
+        ];
+
+        for (s, expected, span) in cases.iter() {
+            let error = ByteUnit::parse_sum(s).unwrap_err();
+            assert_eq!(&error.span(), span, "{:?} failed with {:?}", s, error);
+            assert_eq!(core::mem::discriminant(&error), core::mem::discriminant(expected));
+        }
+
+        // Splitting is linear in the length of the input.
+        extern crate std;
+        let many = "1B ".repeat(100_000);
+        assert_eq!(ByteUnit::parse_sum(many.trim_end()).unwrap(), 100_000.bytes());
+        assert!(ByteUnit::parse_sum(&"1 ".repeat(100_000)).is_err());
+    }
+
+    #[test]
+    fn radix() {
+        use crate::Error;
+
+        assert_parses! {
+            "0x1000" => 4.kibibytes(),
+            "0x1000 B" => 4.kibibytes(),
+            "0xff" => 255.bytes(),
+            "0xFF" => 255.bytes(),
+            "0x10B" => 0x10b.bytes(),
+            "0x10 B" => 16.bytes(),
+            "0x10 EiB" => 16.exbibytes(),
+            "0x1e3" => 0x1e3.bytes(),
+            "0x2kb" => 2.kilobytes(),
+            "0x2 KiB" => 2.kibibytes(),
+            "0x2 kibibytes" => 2.kibibytes(),
+            "0xffffffffffffffff" => ByteUnit::max_value(),
+            "0o17" => 15.bytes(),
+            "0o17 MiB" => 15.mebibytes(),
+            "0b1010" => 10.bytes(),
+            "0b1010 B" => 10.bytes(),
+            "0b1010b" => 10.bytes(),
+            "0b1mb" => 1.megabytes(),
+            "+0x10" => 16.bytes(),
+            "0b" => 0,
+            "0 b" => 0,
+            "0bytes" => 0,
+            "0kb" => 0,
+            "0e3" => 0,
+            "010" => 10.bytes(),
+        }
+
+        assert_reject!["0x", "0o", "0x ", "0xg", "0o8", "0b2", "0X10", "0B1", "00x10", "0x-1"];
+
+        let cases = [
+            ("0x10.5", Error::Unexpected(4, '.'), Some(4..5)),
+            ("0x10e.5", Error::Unexpected(5, '.'), Some(5..6)),
+            ("0b10.1kb", Error::Unexpected(4, '.'), Some(4..5)),
+            ("0x10 kx", Error::BadSuffix(5..7), Some(5..7)),
+            ("0x10_000", Error::Unexpected(4, '_'), Some(4..5)),
+            ("0x10000000000000000", Error::Overflow(0..19), Some(0..19)),
+            ("+0x10000000000000000", Error::Overflow(1..20), Some(1..20)),
+            ("-0x10", Error::Negative(0..1), Some(0..1)),
+        ];
+
+        for (s, expected, span) in cases.iter() {
+            let error = ByteUnit::from_str(s).unwrap_err();
+            assert_eq!(&error.span(), span, "{:?} failed with {:?}", s, error);
+            assert_eq!(core::mem::discriminant(&error), core::mem::discriminant(expected));
+        }
+
+        let max = ByteUnit::max_value();
+        assert_eq!(ByteUnit::from_str_lossy("0x10000000000000000").unwrap(), max);
+    }
+
+    #[test]
+    fn long_fractions() {
+        assert_parses! {
+            "1.99999999999kb" => 1999.bytes(),
+            "0.4_294_967_296kb" => 429.bytes(),
+            "0.99999999999999999999999999999 KiB" => 1023.bytes(),
+            "0.999999999999999999999999999999999 EiB" => ByteUnit::EiB - 1,
+            "1.00000000000000000000000000000001 EiB" => ByteUnit::EiB,
+            "0.1 EB" => 100.petabytes(),
+            "0.3 EB" => 300.petabytes(),
+            "7.06GB" => 7060.megabytes(),
+            "0.000000000000000001 EB" => 1.bytes(),
+            "0.0000000000000000009 EB" => 0,
+            "15.9999999999999999999999 EiB" => ByteUnit::max_value(),
+            "15.999999999999999999 EiB" => ByteUnit::max_value() - 1,
+        }
+
+        // A thousand-digit fraction, without and with a thousand trailing zeros.
+        let mut digits = [b'3'; 1005];
+        digits[..2].copy_from_slice(b"1.");
+        digits[1002..].copy_from_slice(b"KiB");
+
+        let mut zeros = [b'0'; 2005];
+        zeros[..1002].copy_from_slice(&digits[..1002]);
+        zeros[2002..].copy_from_slice(b"KiB");
+
+        let digits = core::str::from_utf8(&digits).unwrap();
+        let zeros = core::str::from_utf8(&zeros).unwrap();
+        assert_eq!(ByteUnit::from_str(digits).unwrap(), 1365.bytes());
+        assert_eq!(ByteUnit::from_str(zeros).unwrap(), 1365.bytes());
+        assert_eq!(ByteUnit::from_str(&digits[..1002]).unwrap_err().span(), Some(1..1002));
+    }
+
+    #[test]
+    fn prefix() {
+        use crate::Error;
+
+        macro_rules! assert_prefix {
+            ($($s:expr => $b:expr, $rest:expr),* $(,)?) => ($(
+                let result = ByteUnit::parse_prefix($s);
+                assert!(result.is_ok(), "{:?} failed to parse: {}", $s, result.unwrap_err());
+                let expected = (ByteUnit::from($b), $rest);
+                assert_eq!(result.unwrap(), expected, "{:?} parsed incorrectly", $s);
+            )*)
+        }
+
+        assert_prefix! {
+            "512MiB then ..." => 512.mebibytes(), " then ...",
+            "512 MiB then" => 512.mebibytes(), " then",
+            "512   MiB" => 512.mebibytes(), "",
+            "512" => 512.bytes(), "",
+            "512 " => 512.bytes(), " ",
+            "512  then" => 512.bytes(), "  then",
+            "512?" => 512.bytes(), "?",
+            "512 MiBx" => 512.mebibytes(), "x",
+            "512 bytes!" => 512.bytes(), "!",
+            "512 kilobytesx" => 512.kilobytes(), "x",
+            "512 megabytesmegabytes" => 512.megabytes(), "megabytes",
+            "1kbps" => 1.kilobytes(), "ps",
+            "1.5 MB." => 1500.kilobytes(), ".",
+            "1. kb" => 1.bytes(), ". kb",
+            "1_000_ kb" => 1.kilobytes(), "_ kb",
+            "1__0" => 1.bytes(), "__0",
+            "1e3 MB" => 1.gigabytes(), "",
+            "1e3e" => 1.kilobytes(), "e",
+            "1e+" => 1.bytes(), "e+",
+            "1eb" => 1.exabytes(), "",
+            "1e 3" => 1.bytes(), "e 3",
+            "+512 KiB" => 512.kibibytes(), "",
+            "0x1000, 0x2000" => 4.kibibytes(), ", 0x2000",
+            "0x10 B ok" => 16.bytes(), " ok",
+            "0x10B ok" => 0x10b.bytes(), " ok",
+            "0b101 b" => 5.bytes(), "",
+            "0b" => 0, "",
+            "0 ü" => 0, " ü",
+            "1 MiB 2 MiB" => 1.mebibytes(), " 2 MiB",
+        }
+
+        assert!(matches!(ByteUnit::parse_prefix(""), Err(Error::Empty)));
+        assert!(matches!(ByteUnit::parse_prefix("then 512"), Err(Error::Unexpected(..))));
+        assert!(matches!(ByteUnit::parse_prefix("-5 MB"), Err(Error::Negative(_))));
+        assert!(matches!(ByteUnit::parse_prefix("++5 MB"), Err(Error::Unexpected(1, '+'))));
+        assert!(matches!(ByteUnit::parse_prefix("1.5 ok"), Err(Error::FractionalByte(_))));
+        assert!(matches!(ByteUnit::parse_prefix("99999999999999999999B"), Err(Error::Overflow(_))));
+
+        // `FromStr` accepts exactly the inputs that are their own prefix.
+        let inputs = ["1_000 kB", "1.5e3 KiB", "0x10 B", "+2 megabytes", "1e+2", "1e+", "1,000",
+            "1 kb s", "1MB ", "0b", "1. kb", "1eb", "1e3e", "2\u{a0}MB"];
+
+        for s in &inputs {
+            match (ByteUnit::from_str(s), ByteUnit::parse_prefix(s)) {
+                (Ok(value), prefix) => assert_eq!(prefix.unwrap(), (value, ""), "{:?}", s),
+                (Err(_), Ok((_, rest))) => assert!(!rest.is_empty(), "{:?}", s),
+                (Err(_), Err(_)) => continue,
+            }
+        }
+    }
+
+    #[test]
+    fn shorthand() {
+        macro_rules! assert_shorthand {
+            ($($s:expr => $b:expr),* $(,)?) => ($(
+                let result = ByteUnit::from_str_shorthand($s);
+                assert!(result.is_ok(), "{:?} failed to parse: {}", $s, result.unwrap_err());
+                assert_eq!(result.unwrap(), $b, "{:?} parsed incorrectly", $s);
+            )*)
+        }
+
+        assert_shorthand! {
+            "512k" => 512.kibibytes(),
+            "512K" => 512.kibibytes(),
+            "99k" => 99.kibibytes(),
+            "512 k" => 512.kibibytes(),
+            "2m" => 2.mebibytes(),
+            "2g" => 2.gibibytes(),
+            "2G" => 2.gibibytes(),
+            "2t" => 2 * ByteUnit::TiB,
+            "2p" => 2.pebibytes(),
+            "2e" => 2.exbibytes(),
+            "2E" => 2.exbibytes(),
+            "2 e" => 2.exbibytes(),
+            "0.5k" => 512.bytes(),
+            "1e3k" => 1000.kibibytes(),
+            "1e3" => 1.kilobytes(),
+            "1e" => 1.exbibytes(),
+            "+1k" => 1.kibibytes(),
+            "1_024k" => 1.mebibytes(),
+            "1kb" => 1.kilobytes(),
+            "1 megabyte" => 1.megabytes(),
+            "1" => 1.bytes(),
+            "1b" => 1.bytes(),
+            "16e" => ByteUnit::max_value(),
+        }
+
+        for s in &["99k", "2g", "512 m", "1t", "1p", "1E"] {
+            assert!(ByteUnit::from_str(s).is_err(), "{:?} parsed", s);
+        }
+
+        for s in &["1x", "1kk", "1 i", "1.5b", "1e+", "k", "1 kib s"] {
+            assert!(ByteUnit::from_str_shorthand(s).is_err(), "{:?} parsed", s);
+        }
+    }
+
+    #[test]
+    fn parse_const() {
+        const LIMIT: ByteUnit = ByteUnit::parse_const("512MiB");
+        const HALF_GIB: ByteUnit = ByteUnit::parse_const("0.5 gib");
+        assert_eq!(LIMIT, 512.mebibytes());
+        assert_eq!(HALF_GIB, 512.mebibytes());
+
+        let cases = [
+            "0", "1", "512", "512b", "512 B", "1kb", "1 kB", "1KiB", "1.5 KiB", "0.5MB",
+            "7.06GB", "7.25 gb", "3 MiB", "2 TB", "2 TiB", "2 PB", "2 pib", "2 EB", "2EiB",
+            "16 EiB", "99999999999999999999", "1.00000000000000000000001 EiB", "20.5MB",
+            "0.000000000000000001 EB", "01MB", "0001MiB", "9.00000000000000000000MB",
+        ];
+
+        for s in cases.iter() {
+            assert_eq!(ByteUnit::parse_const(s), ByteUnit::from_str_lossy(s).unwrap(), "{:?}", s);
+        }
+    }
+
+    #[test]
+    fn parse_const_rejects_all() {
+        extern crate std;
+
+        let cases = [
+            "", " ", "b", "kb", ".5", "1.", "1. kb", "1.5", "1.5 b", "1 kx", "1 ", "1 kb ",
+            " 1", "1e3", "+1", "-1", "1_000", "1,000", "1 megabyte", "1k", "0x10", "1.2.3",
+        ];
+
+        for s in cases.iter() {
+            let result = std::panic::catch_unwind(|| ByteUnit::parse_const(s));
+            assert!(result.is_err(), "{:?} parsed as {:?}", s, result);
+        }
+    }
+
+    #[test]
+    fn parse_with() {
+        use crate::ParseOptions;
+
+        let default = ParseOptions::default();
+        let cases = ["1", "1.5 MiB", "+1kb", "1_000", "1e3", "0x10", "5 megabytes", "1,000",
+            "1k", "1 KB", "99999999999999999999", "1e", "-1", ""];
+
+        for s in cases.iter() {
+            let expected = ByteUnit::from_str(s);
+            let actual = ByteUnit::parse_with(s, &default);
+            assert_eq!(expected.as_ref().ok(), actual.as_ref().ok(), "{:?}", s);
+            assert_eq!(expected.err().map(|e| e.span()), actual.err().map(|e| e.span()));
+        }
+
+        let none = ParseOptions::new()
+            .allow_plus_sign(false)
+            .allow_underscores(false)
+            .allow_exponents(false)
+            .allow_radix(false)
+            .allow_long_names(false);
+
+        for s in ["+1kb", "1_000", "1e3", "1e3 kB", "0x10", "0b1", "5 megabytes", "1 byte"].iter() {
+            assert!(ByteUnit::parse_with(s, &none).is_err(), "{:?}", s);
+        }
+
+        for s in ["1", "1.5 MiB", "1kb", "1000", "1 EB", "1eb", "1 B", "0b", "0"].iter() {
+            assert_eq!(ByteUnit::parse_with(s, &none).unwrap(), ByteUnit::from_str(s).unwrap());
+        }
+
+        let binary = crate::ParseOptions::new().default_binary(true);
+        assert_eq!(ByteUnit::parse_with("1 kB", &binary).unwrap(), 1.kibibytes());
+        assert_eq!(ByteUnit::parse_with("1 KiB", &binary).unwrap(), 1.kibibytes());
+        assert_eq!(ByteUnit::parse_with("1.5 MB", &binary).unwrap(), 1536.kibibytes());
+        assert_eq!(ByteUnit::parse_with("2 gigabytes", &binary).unwrap(), 2.gibibytes());
+        assert_eq!(ByteUnit::parse_with("2 TB", &binary).unwrap(), 2 * ByteUnit::TiB);
+        assert_eq!(ByteUnit::parse_with("2 PB", &binary).unwrap(), 2.pebibytes());
+        assert_eq!(ByteUnit::parse_with("2 EB", &binary).unwrap(), 2.exbibytes());
+        assert_eq!(ByteUnit::parse_with("0x2 EB", &binary).unwrap(), 2.exbibytes());
+        assert_eq!(ByteUnit::parse_with("2 B", &binary).unwrap(), 2.bytes());
+
+        let all = ParseOptions::new()
+            .allow_grouping(true)
+            .allow_shorthand(true)
+            .saturate(true);
+
+        assert_eq!(ByteUnit::parse_with("1,024k", &all).unwrap(), 1.mebibytes());
+        assert_eq!(ByteUnit::parse_with("+1,024 m", &all).unwrap(), 1.gibibytes());
+        assert_eq!(ByteUnit::parse_with("99,999,999,999,999,999,999", &all).unwrap(),
+            ByteUnit::max_value());
+    }
+
+    #[test]
+    fn suffix_first() {
+        extern crate std;
+        use std::format;
+        use crate::{Error, ParseOptions};
+
+        let opts = ParseOptions::new().allow_suffix_first(true);
+        let parse = |s: &str| ByteUnit::parse_with(s, &opts);
+
+        for &(suffix, _) in super::SUFFIXES {
+            for count in [0, 1, 4, 1000] {
+                let forward = ByteUnit::from_str(&format!("{}{}", count, suffix)).unwrap();
+                assert_eq!(parse(&format!("{}x{}", suffix, count)).unwrap(), forward);
+                assert_eq!(parse(&format!("{}X{}", suffix, count)).unwrap(), forward);
+            }
+        }
+
+        assert_eq!(parse("mebibytesx4").unwrap(), 4.mebibytes());
+        assert_eq!(parse("exbibytex2").unwrap(), 2.exbibytes());
+        assert_eq!(parse("bx7").unwrap(), 7.bytes());
+        assert_eq!(parse("EiBx16").unwrap(), ByteUnit::max_value());
+        assert_eq!(parse("+MiBx4").unwrap(), 4.mebibytes());
+        assert_eq!(parse("1.5 MiB").unwrap(), 1536.kibibytes());
+
+        let opts = opts.allow_shorthand(true).bit_aware(true).saturate(true);
+        assert_eq!(ByteUnit::parse_with("kx3", &opts).unwrap(), 3.kibibytes());
+        assert_eq!(ByteUnit::parse_with("Mbx8", &opts).unwrap(), 1.megabytes());
+        assert_eq!(ByteUnit::parse_with("kBx99999999999999999999", &opts).unwrap(),
+            ByteUnit::max_value());
+
+        assert!(matches!(parse("MiB"), Err(Error::BadWhole(ref r, _)) if *r == (3..3)));
+        assert!(matches!(parse("MiBx"), Err(Error::BadWhole(ref r, _)) if *r == (4..4)));
+        assert!(matches!(parse("MiBx1.5"), Err(Error::Unexpected(5, '.'))));
+        assert!(matches!(parse("MiBx-1"), Err(Error::Unexpected(4, '-'))));
+        assert!(matches!(parse("MiB x4"), Err(Error::BadSuffix(ref r)) if *r == (0..4)));
+        assert!(matches!(parse("MiXx4"), Err(Error::BadSuffix(ref r)) if *r == (0..3)));
+        assert!(matches!(parse("kBx99999999999999999999"), Err(Error::Overflow(ref r))
+            if *r == (3..23)));
+        assert!(ByteUnit::from_str("MiBx4").is_err());
+        assert!(ByteUnit::parse_with("MiBx4", &ParseOptions::new()).is_err());
+    }
+
+    #[test]
+    fn bit_aware() {
+        macro_rules! assert_bits {
+            ($($s:expr => $b:expr),* $(,)?) => ($(
+                let result = ByteUnit::from_str_bit_aware($s);
+                assert!(result.is_ok(), "{:?} failed to parse: {}", $s, result.unwrap_err());
+                assert_eq!(result.unwrap(), $b, "{:?} parsed incorrectly", $s);
+            )*)
+        }
+
+        assert_bits! {
+            "1MB" => 1.megabytes(),
+            "1Mb" => 125.kilobytes(),
+            "1mb" => 125.kilobytes(),
+            "1mB" => 1.megabytes(),
+            "1 Mb" => 125.kilobytes(),
+            "1kb" => 125.bytes(),
+            "1kB" => 1.kilobytes(),
+            "1Kib" => 128.bytes(),
+            "1KiB" => 1.kibibytes(),
+            "8 Mib" => 1.mebibytes(),
+            "8 Gib" => 1.gibibytes(),
+            "8 Tib" => ByteUnit::TiB,
+            "8 Pib" => 1.pebibytes(),
+            "8 Eib" => 1.exbibytes(),
+            "128 Eib" => 16.exbibytes(),
+            "8 Gb" => 1.gigabytes(),
+            "1.5 kb" => 187.bytes(),
+            "0.5 Mib" => 64.kibibytes(),
+            "1e3 kb" => 125.kilobytes(),
+            "1b" => 0,
+            "7b" => 0,
+            "8b" => 1.bytes(),
+            "12b" => 1.bytes(),
+            "12 b" => 1.bytes(),
+            "12B" => 12.bytes(),
+            "12" => 12.bytes(),
+            "1.6e1b" => 2.bytes(),
+            "0x10 b" => 2.bytes(),
+            "0x10 kb" => 2.kilobytes(),
+            "0x10 B" => 16.bytes(),
+            "5 kilobytes" => 5.kilobytes(),
+            "18446744073709551615 b" => ByteUnit::max_value() / 8,
+        }
+
+        assert!(ByteUnit::from_str_bit_aware("1.5b").is_err());
+        assert!(ByteUnit::from_str_bit_aware("99999999999999999999 b").is_err());
+        assert_eq!(ByteUnit::from_str("1Mb").unwrap(), 1.megabytes());
+        assert_eq!(ByteUnit::from_str("12b").unwrap(), 12.bytes());
+    }
+
+    #[test]
+    fn rate() {
+        use core::time::Duration;
+        use crate::{ByteRate, Error};
+
+        let rate = |s: &str| s.parse::<ByteRate>();
+        let secs = Duration::from_secs;
+        assert_eq!(rate("10MB/s").unwrap(), 10.megabytes().per(secs(1)));
+        assert_eq!(rate("10 MB / s").unwrap(), 10.megabytes().per(secs(1)));
+        assert_eq!(rate("1GiB/min").unwrap(), 1.gibibytes().per(secs(60)));
+        assert_eq!(rate("1.5 kb/Seconds").unwrap(), 1500.bytes().per(secs(1)));
+        assert_eq!(rate("5KiB/ms").unwrap().per_second(), 5_120_000);
+        assert_eq!(rate("1 EiB/ns").unwrap().per_second(), ByteUnit::max_value());
+        assert_eq!(rate("24 B/d").unwrap().per_second(), 0);
+        assert_eq!(rate("3600B/hr").unwrap().per_second(), 1);
+        assert_eq!(rate("2 bytes/hours").unwrap().duration(), secs(3600));
+
+        assert!(matches!(rate("10MB"), Err(Error::BadRate(span)) if span == (4..4)));
+        assert!(matches!(rate("10MB/"), Err(Error::BadRate(span)) if span == (5..5)));
+        assert!(matches!(rate("10MB/ yr"), Err(Error::BadRate(span)) if span == (6..8)));
+        assert!(matches!(rate("10MX/s"), Err(Error::BadSuffix(span)) if span == (2..4)));
+        assert!(rate("/s").is_err());
+        assert!(rate("10MB//s").is_err());
+    }
+
+    #[test]
+    fn huge_units() {
+        assert_parses! {
+            "0 ZB" => 0,
+            "0.0 YiB" => 0,
+            "1 ZB" => ByteUnit::max_value(),
+            "1 yobibyte" => ByteUnit::max_value(),
+            "0.001 ZB" => 1.exabytes(),
+            "0.001zb" => 1.exabytes(),
+            "0.000001 YB" => 1.exabytes(),
+            "0.000_001 yottabytes" => 1.exabytes(),
+            "0.0078125 ZiB" => 8.exbibytes(),
+            "0.5e-3 ZB" => 500.petabytes(),
+            "1e-6 YB" => 1.exabytes(),
+            "0.0009765625 zebibytes" => 1.exbibytes(),
+        }
+
+        assert_eq!(ByteUnit::Zettabyte(0), 0);
+        assert_eq!(ByteUnit::Zettabyte(1), ByteUnit::max_value());
+        assert_eq!(ByteUnit::Yobibyte(u64::MAX), ByteUnit::max_value());
+        assert_eq!(ByteUnit::ZiB, ByteUnit::max_value());
+
+        let binary = crate::ParseOptions::new().default_binary(true);
+        let parse = |s| ByteUnit::parse_with(s, &binary).unwrap();
+        assert_eq!(parse("0.0009765625 ZB"), 1.exbibytes());
+        assert_eq!(parse("1 EB"), 1.exbibytes());
+
+        let shorthand = |s| ByteUnit::from_str_shorthand(s).unwrap();
+        assert_eq!(shorthand("0.0009765625z"), 1.exbibytes());
+        assert_eq!(shorthand("0.00000095367431640625Y"), 1.exbibytes());
+        assert_eq!(ByteUnit::parse_const("0.001 ZB"), 1.exabytes());
+        assert_eq!(ByteUnit::parse_const("2 YiB"), ByteUnit::max_value());
+        assert_eq!(ByteUnit::parse_prefix("0.001ZB!").unwrap(), (1.exabytes(), "!"));
+        assert_eq!(ByteUnit::parse_prefix("5 zettabytes").unwrap().1, "");
+    }
+
+    #[test]
+    fn bad_exponent() {
+        use crate::Error;
+
+        for s in &["1e", "1e+", "1e-", "1.5e", "1e+ kb", "1E-MB"] {
+            match ByteUnit::from_str(s) {
+                Err(Error::BadExponent(..)) => continue,
+                result => panic!("{:?} parsed as {:?}", s, result),
+            }
+        }
+    }
+
+    #[test]
+    fn whitespace() {
+        extern crate std;
+        use std::format;
+        use crate::Error;
+
+        for (&(suffix, size), &(_, name)) in super::SUFFIXES.iter().zip(super::UNIT_NAMES) {
+            let expected = super::saturate(2 * size);
+            for space in ["", " ", "   ", "\u{a0}"] {
+                // A hex digit suffix would be consumed by the number: `0x2B`.
+                let radix_space = if space.is_empty() { " " } else { space };
+                let inputs = [
+                    format!("2{}{}", space, suffix),
+                    format!("2{}{}s", space, name),
+                    format!("0x2{}{}", radix_space, suffix),
+                ];
+
+                for s in &inputs {
+                    assert_eq!(ByteUnit::from_str(s).unwrap(), expected, "{:?}", s);
+                    assert_eq!(ByteUnit::parse_prefix(s).unwrap(), (expected, ""), "{:?}", s);
+                }
+            }
+        }
+
+        assert_eq!(ByteUnit::parse_const("2\u{a0}KiB"), 2.kibibytes());
+        assert_eq!(ByteUnit::parse_prefix("2\u{a0}MB/s").unwrap(), (2.megabytes(), "/s"));
+        assert_eq!(ByteUnit::parse_sum("1\u{a0}MiB 512\u{a0}KiB").unwrap(), 1536.kibibytes());
+        assert_eq!("0x10\u{a0}B".parse::<ByteUnit>().unwrap(), 16.bytes());
+        assert_eq!("1.5\u{a0}GB".parse::<ByteUnit>().unwrap(), 1500.megabytes());
+
+        assert_reject!["2\u{a0}\u{a0}kB", "2 \u{a0}kB", "2\u{a0} kB", "2\tkB", "2\u{2009}kB"];
+        assert_reject!["2kB\u{a0}", "\u{a0}2kB", "2\u{a0}", "0x2\u{a0}\u{a0}B"];
+        assert!(matches!(ByteUnit::from_str("\u{a0}1"), Err(Error::Unexpected(2, '1'))));
+        assert_eq!(ByteUnit::from_str("1\u{a0}kx").unwrap_err().span(), Some(3..5));
+        assert_eq!(ByteUnit::from_str("1\u{a0}k?").unwrap_err().span(), Some(4..5));
     }
 
     #[test]
@@ -184,5 +1994,110 @@ mod parse_tests {
             "9.00000000000000000000MB" => 9.megabytes(),
             "9.000000000000000000000000000000MB" => 9.megabytes(),
         }
+
+        assert_parses! {
+            "1e6MB" => 1.terabytes(),
+            "1e6 MB" => 1.terabytes(),
+            "1E6MB" => 1.terabytes(),
+            "1e+6 MB" => 1.terabytes(),
+            "9.5e3 KiB" => 9500.kibibytes(),
+            "1e3" => 1.kilobytes(),
+            "1.5e3" => 1500.bytes(),
+            "1.5e3b" => 1500.bytes(),
+            "1e0kb" => 1.kilobytes(),
+            "1e-3 MB" => 1.kilobytes(),
+            "2.5e-1 KiB" => 256.bytes(),
+            "1e5eb" => ByteUnit::max_value(),
+            "1e20" => ByteUnit::max_value(),
+            "1e400 KiB" => ByteUnit::max_value(),
+            "1e-400 KiB" => 0,
+            "0e9EiB" => 0,
+            "2.3e2" => 230.bytes(),
+            "0.29e2" => 29.bytes(),
+            "4.35e2" => 435.bytes(),
+            "2.3e2 KB" => 230.kilobytes(),
+            "1.15e2 kB" => 115.kilobytes(),
+            "1.15e-1 kB" => 115.bytes(),
+            "12.5e-1 KiB" => 1280.bytes(),
+            "0.000001e6" => 1.bytes(),
+            "1.8446744073709551615e19" => ByteUnit::max_value(),
+            "1.8446744073709551616e19" => ByteUnit::max_value(),
+            "1e-2147483648 EiB" => 0,
+            "1e2147483647" => ByteUnit::max_value(),
+            "1e2147483648" => ByteUnit::max_value(),
+            "1e99999999999" => ByteUnit::max_value(),
+            "0.001e+99999999999999999999 kB" => ByteUnit::max_value(),
+            "1e-2147483649 kB" => 0,
+            "1e-99999999999 kB" => 0,
+            "12.5e-99999999999999999999 EiB" => 0,
+        }
+
+        assert_parses! {
+            "+512MiB" => 512.mebibytes(),
+            "+512 MiB" => 512.mebibytes(),
+            "+0" => 0,
+            "+1.5kb" => 1500.bytes(),
+            "+1e3" => 1.kilobytes(),
+            "+1_000" => 1.kilobytes(),
+            "+5 megabytes" => 5.megabytes(),
+        }
+
+        assert_parses! {
+            "1 byte" => 1.bytes(),
+            "5 bytes" => 5.bytes(),
+            "5bytes" => 5.bytes(),
+            "5 BYTES" => 5.bytes(),
+            "5 megabytes" => 5.megabytes(),
+            "5megabytes" => 5.megabytes(),
+            "5 MegaBytes" => 5.megabytes(),
+            "512 kibibytes" => 512.kibibytes(),
+            "1 gigabyte" => 1.gigabytes(),
+            "1 kilobyte" => 1.kilobytes(),
+            "1.5 kilobytes" => 1500.bytes(),
+            "2 mebibytes" => 2.mebibytes(),
+            "2 gibibytes" => 2.gibibytes(),
+            "2 terabytes" => 2.terabytes(),
+            "2 tebibytes" => 2 * ByteUnit::TiB,
+            "2 petabytes" => 2.petabytes(),
+            "2 pebibytes" => 2.pebibytes(),
+            "2 exabytes" => 2.exabytes(),
+            "1 exbibyte" => 1.exbibytes(),
+            "1e3 bytes" => 1.kilobytes(),
+        }
+
+        assert_parses! {
+            "1_048_576" => 1.mebibytes(),
+            "10_000 kB" => 10.megabytes(),
+            "10_000kB" => 10.megabytes(),
+            "1_0" => 10.bytes(),
+            "1_5.2_5 KiB" => 15.kibibytes() + 256.bytes(),
+            "0.000_5 MB" => 500.bytes(),
+            "0_000_000_000_000_000_000_000_001" => 1.bytes(),
+            "18_446_744_073_709_551_615" => ByteUnit::max_value(),
+            "1_000e3" => 1.megabytes(),
+            "1_0.5e1kb" => 105.kilobytes(),
+        }
+    }
+
+    #[test]
+    fn test_error_source() {
+        use core::error::Error as _;
+
+        let error = "1e+kb".parse::<ByteUnit>().unwrap_err();
+        assert!(matches!(error, crate::Error::BadExponent(..)));
+        assert!(error.source().unwrap().is::<core::num::ParseIntError>());
+
+        let error = "5.kb".parse::<ByteUnit>().unwrap_err();
+        assert!(matches!(error, crate::Error::BadFractional(..)));
+        assert!(error.source().unwrap().is::<core::num::ParseIntError>());
+
+        for input in ["", "1 parsec", "-1", "1.5B", "1 MiB/fortnight"] {
+            let error = match input.parse::<crate::ByteRate>() {
+                Ok(_) => panic!("{:?} should fail to parse", input),
+                Err(error) => error,
+            };
+
+            assert!(error.source().is_none(), "{:?}", error);
+        }
     }
 }
@@ -31,7 +31,7 @@ impl<'de> de::Visitor<'de> for Visitor {
     type Value = ByteUnit;
 
     fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
-        formatter.write_str("a byte unit as an integer or string")
+        formatter.write_str("a byte unit as an integer, float, or string")
     }
 
     visit_integer_fn!(visit_i8: i8);
@@ -46,20 +46,252 @@ impl<'de> de::Visitor<'de> for Visitor {
     visit_integer_fn!(visit_u64: u64);
     visit_integer_fn!(visit_u128: u128);
 
+    fn visit_f32<E: de::Error>(self, v: f32) -> Result<Self::Value, E> {
+        self.visit_f64(v as f64)
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+        if v.is_nan() || v < 0.0 {
+            return Err(E::invalid_value(de::Unexpected::Float(v), &self));
+        }
+
+        Ok(ByteUnit::from_f64(v, crate::Rounding::Floor))
+    }
+
     fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
-        v.parse().map_err(|_| E::invalid_value(de::Unexpected::Str(v), &"byte unit string"))
+        parse_str(v)
     }
 }
 
+/// Parses `v` as a `ByteUnit`, including the parse error, if any, in the
+/// deserialization error.
+fn parse_str<E: de::Error>(v: &str) -> Result<ByteUnit, E> {
+    v.parse().map_err(|e| E::custom(format_args!("invalid byte unit string {:?}: {}", v, e)))
+}
+
 impl Serialize for ByteUnit {
     fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         serializer.serialize_u64(self.as_u64())
     }
 }
 
+/// (De)serialize a [`ByteUnit`] as a human-readable string.
+///
+/// For use with `#[serde(with = "ubyte::serde::as_str")]`. A `ByteUnit` is
+/// serialized as a string in the largest unit that represents it exactly, so
+/// that it round-trips losslessly: `512.kibibytes()` serializes as `"512KiB"`,
+/// `1500.kilobytes()` as `"1500kB"`, and `1025.bytes()` as `"1025B"`.
+/// Deserialization is exactly that of `ByteUnit`'s `Deserialize`
+/// implementation and thus accepts both strings and integers.
+///
+/// # Example
+///
+/// ```rust
+/// use ubyte::ToByteUnit;
+///
+/// // With `serde` derive, annotate a field of type `ByteUnit` with:
+/// // #[serde(with = "ubyte::serde::as_str")]
+/// let mut json = Vec::new();
+/// let mut serializer = serde_json::Serializer::new(&mut json);
+/// ubyte::serde::as_str::serialize(&512.kibibytes(), &mut serializer).unwrap();
+/// assert_eq!(json, br#""512KiB""#);
+///
+/// let mut deserializer = serde_json::Deserializer::from_slice(&json);
+/// let cache = ubyte::serde::as_str::deserialize(&mut deserializer).unwrap();
+/// assert_eq!(cache, 512.kibibytes());
+/// ```
+pub mod as_str {
+    use serde::{Serializer, Deserializer, Deserialize};
+
+    use crate::ByteUnit;
+
+    /// Serializes `value` as a string in the largest unit that represents
+    /// it exactly.
+    pub fn serialize<S: Serializer>(value: &ByteUnit, serializer: S) -> Result<S::Ok, S::Error> {
+        const UNITS: &[(ByteUnit, &str)] = &[
+            (ByteUnit::EiB, "EiB"), (ByteUnit::EB, "EB"), (ByteUnit::PiB, "PiB"),
+            (ByteUnit::PB, "PB"), (ByteUnit::TiB, "TiB"), (ByteUnit::TB, "TB"),
+            (ByteUnit::GiB, "GiB"), (ByteUnit::GB, "GB"), (ByteUnit::MiB, "MiB"),
+            (ByteUnit::MB, "MB"), (ByteUnit::KiB, "KiB"), (ByteUnit::kB, "kB"),
+        ];
+
+        let n = value.as_u64();
+        let (unit, suffix) = UNITS.iter().copied()
+            .find(|&(unit, _)| n != 0 && n % unit.as_u64() == 0)
+            .unwrap_or((ByteUnit::B, "B"));
+
+        serializer.collect_str(&format_args!("{}{}", n / unit.as_u64(), suffix))
+    }
+
+    /// Deserializes a `ByteUnit` exactly as its `Deserialize` implementation
+    /// does.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<ByteUnit, D::Error> {
+        ByteUnit::deserialize(deserializer)
+    }
+}
+
+/// (De)serialize a [`ByteUnit`] as its `Display` string, regardless of format.
+///
+/// For use with `#[serde(with = "ubyte::serde::string")]`. A `ByteUnit` is
+/// serialized as the string produced by its `Display` implementation, such as
+/// `"512KiB"` or `"7.06GB"`, and deserialized from a string via its `FromStr`
+/// implementation. Integers are rejected.
+///
+/// Because `Display` rounds to two decimal places, serialization is lossy for
+/// values that aren't displayed exactly. Use [`as_str`](crate::serde::as_str) to
+/// serialize into a string that always round-trips exactly.
+///
+/// # Example
+///
+/// ```rust
+/// use ubyte::ToByteUnit;
+///
+/// // With `serde` derive, annotate a field of type `ByteUnit` with:
+/// // #[serde(with = "ubyte::serde::string")]
+/// let mut json = Vec::new();
+/// let mut serializer = serde_json::Serializer::new(&mut json);
+/// ubyte::serde::string::serialize(&512.kibibytes(), &mut serializer).unwrap();
+/// assert_eq!(json, br#""512KiB""#);
+///
+/// let mut deserializer = serde_json::Deserializer::from_slice(&json);
+/// let cache = ubyte::serde::string::deserialize(&mut deserializer).unwrap();
+/// assert_eq!(cache, 512.kibibytes());
+///
+/// let mut deserializer = serde_json::Deserializer::from_str("524288");
+/// assert!(ubyte::serde::string::deserialize(&mut deserializer).is_err());
+/// ```
+pub mod string {
+    use serde::{de, Serializer, Deserializer};
+
+    use crate::ByteUnit;
+
+    /// Serializes `value` as its `Display` string.
+    pub fn serialize<S: Serializer>(value: &ByteUnit, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(value)
+    }
+
+    /// Deserializes a `ByteUnit` from a string via its `FromStr`
+    /// implementation.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<ByteUnit, D::Error> {
+        deserializer.deserialize_str(StrVisitor)
+    }
+
+    struct StrVisitor;
+
+    impl<'de> de::Visitor<'de> for StrVisitor {
+        type Value = ByteUnit;
+
+        fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+            formatter.write_str("a byte unit string")
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            super::parse_str(v)
+        }
+    }
+}
+
+/// (De)serialize a [`ByteUnit`] as a string in a fixed unit.
+///
+/// Each submodule, from [`b`](unit::b) to [`eib`](unit::eib), is for use with
+/// `#[serde(with = "...")]`, for example `#[serde(with =
+/// "ubyte::serde::unit::mib")]`. A `ByteUnit` is serialized as a string in
+/// the submodule's unit, with a space before the suffix, regardless of its
+/// magnitude: `512.mebibytes()` and `1536.kibibytes()` serialize as `"512
+/// MiB"` and `"1.5 MiB"`, respectively. Values that aren't a whole multiple of
+/// the unit are written with as many fractional digits as needed to
+/// round-trip exactly. A `ByteUnit` is deserialized from a string via its
+/// `FromStr` implementation, exactly as with [`string`](crate::serde::string).
+///
+/// To serialize in a unit chosen at runtime, use [`serialize_in()`](unit::serialize_in) from a
+/// custom `serialize_with` function.
+///
+/// # Example
+///
+/// ```rust
+/// use ubyte::ToByteUnit;
+///
+/// // With `serde` derive, annotate a field of type `ByteUnit` with:
+/// // #[serde(with = "ubyte::serde::unit::mib")]
+/// let mut toml = Vec::new();
+/// let mut serializer = serde_json::Serializer::new(&mut toml);
+/// ubyte::serde::unit::mib::serialize(&1536.kibibytes(), &mut serializer).unwrap();
+/// assert_eq!(toml, br#""1.5 MiB""#);
+///
+/// let mut deserializer = serde_json::Deserializer::from_slice(&toml);
+/// let value = ubyte::serde::unit::mib::deserialize(&mut deserializer).unwrap();
+/// assert_eq!(value, 1536.kibibytes());
+/// ```
+pub mod unit {
+    use serde::Serializer;
+
+    use crate::{ByteUnit, Rounding};
+
+    /// Serializes `value` as a string in `unit`, which must be one of the
+    /// `1`-valued unit constants from [`ByteUnit::B`] to [`ByteUnit::EiB`];
+    /// any other `unit` is treated as `ByteUnit::B`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ubyte::{ByteUnit, ToByteUnit};
+    ///
+    /// fn in_kib<S: serde::Serializer>(v: &ByteUnit, s: S) -> Result<S::Ok, S::Error> {
+    ///     ubyte::serde::unit::serialize_in(v, ByteUnit::KiB, s)
+    /// }
+    ///
+    /// let mut json = Vec::new();
+    /// in_kib(&2.mebibytes(), &mut serde_json::Serializer::new(&mut json)).unwrap();
+    /// assert_eq!(json, br#""2048 KiB""#);
+    /// ```
+    pub fn serialize_in<S: Serializer>(
+        value: &ByteUnit,
+        unit: ByteUnit,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        // Rounding up to 19 digits, less than a byte for any unit, and then
+        // flooring when parsing recovers the exact value.
+        let display = value.display_in(unit).rounded(Rounding::Ceil).trimmed();
+        serializer.collect_str(&format_args!("{:#.19}", display))
+    }
+
+    macro_rules! unit_modules {
+        ($($name:ident => $unit:ident),*) => ($(
+            #[doc = concat!("(De)serialize a `ByteUnit` as a string in `", stringify!($unit), "`.")]
+            pub mod $name {
+                use serde::{Serializer, Deserializer};
+
+                use crate::ByteUnit;
+
+                #[doc = concat!("Serializes `value` as a string in `", stringify!($unit), "`.")]
+                pub fn serialize<S: Serializer>(
+                    value: &ByteUnit,
+                    serializer: S,
+                ) -> Result<S::Ok, S::Error> {
+                    super::serialize_in(value, ByteUnit::$unit, serializer)
+                }
+
+                /// Deserializes a `ByteUnit` from a string via its `FromStr`
+                /// implementation.
+                pub fn deserialize<'de, D: Deserializer<'de>>(
+                    deserializer: D,
+                ) -> Result<ByteUnit, D::Error> {
+                    crate::serde::string::deserialize(deserializer)
+                }
+            }
+        )*)
+    }
+
+    unit_modules! {
+        b => B, kb => kB, kib => KiB, mb => MB, mib => MiB, gb => GB, gib => GiB,
+        tb => TB, tib => TiB, pb => PB, pib => PiB, eb => EB, eib => EiB
+    }
+}
+
 #[cfg(test)]
 mod serde_tests {
-    use serde_test::{assert_de_tokens, assert_ser_tokens, Configure, Token};
+    use serde_test::{assert_de_tokens, assert_de_tokens_error, assert_ser_tokens};
+    use serde_test::{Configure, Readable, Token};
     use crate::ByteUnit;
 
     #[test]
@@ -88,6 +320,55 @@ mod serde_tests {
         assert_de_tokens(&zero, &[Token::I64(-2483)]);
     }
 
+    #[test]
+    fn test_de_float() {
+        let value = ByteUnit::Kilobyte(1500).readable();
+        assert_de_tokens(&value, &[Token::F64(1.5e6)]);
+        assert_de_tokens(&value, &[Token::F64(1.5e6 + 0.9)]);
+        assert_de_tokens(&value, &[Token::F32(1.5e6)]);
+
+        let zero = ByteUnit::Byte(0).readable();
+        assert_de_tokens(&zero, &[Token::F64(0.0)]);
+        assert_de_tokens(&zero, &[Token::F64(-0.0)]);
+        assert_de_tokens(&zero, &[Token::F64(0.75)]);
+
+        let max = ByteUnit::max_value().readable();
+        assert_de_tokens(&max, &[Token::F64(1e20)]);
+        assert_de_tokens(&max, &[Token::F64(f64::INFINITY)]);
+
+        let expected = "invalid value: floating point `-1.0`, \
+            expected a byte unit as an integer, float, or string";
+        assert_de_tokens_error::<Readable<ByteUnit>>(&[Token::F64(-1.0)], expected);
+
+        let expected = "invalid value: floating point `NaN`, \
+            expected a byte unit as an integer, float, or string";
+        assert_de_tokens_error::<Readable<ByteUnit>>(&[Token::F64(f64::NAN)], expected);
+
+        let value: ByteUnit = serde_json::from_str("1.5e6").unwrap();
+        assert_eq!(value, ByteUnit::Kilobyte(1500));
+        assert!(serde_json::from_str::<ByteUnit>("-2.5").is_err());
+    }
+
+    #[test]
+    fn test_de_error() {
+        let expected = "invalid byte unit string \"12 flurbs\": \
+            unknown or malformed byte unit suffix at index `3`";
+        assert_de_tokens_error::<Readable<ByteUnit>>(&[Token::Str("12 flurbs")], expected);
+
+        let expected = "invalid byte unit string \"\": the input was empty";
+        assert_de_tokens_error::<Readable<ByteUnit>>(&[Token::Str("")], expected);
+
+        let expected = "invalid byte unit string \"1.5B\": \
+            unit `B` cannot have a fractional component at index `1`";
+        assert_de_tokens_error::<Readable<ByteUnit>>(&[Token::Str("1.5B")], expected);
+
+        extern crate std;
+        use std::string::ToString;
+
+        let error = serde_json::from_str::<ByteUnit>(r#""99 zorks""#).unwrap_err();
+        assert!(error.to_string().contains("suffix at index `3`"), "{}", error);
+    }
+
     #[test]
     fn test_de_compact() {
         let half_mib = ByteUnit::Kibibyte(512).compact();
@@ -130,4 +411,133 @@ mod serde_tests {
         let zero = ByteUnit::Byte(0).readable();
         assert_de_tokens(&zero, &[Token::U64(0)]);
     }
+
+    struct AsStr(ByteUnit);
+
+    impl serde::Serialize for AsStr {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            super::as_str::serialize(&self.0, serializer)
+        }
+    }
+
+    #[test]
+    fn test_ser_as_str() {
+        let cases = [
+            (ByteUnit::Kibibyte(512), "512KiB"),
+            (ByteUnit::Kilobyte(1500), "1500kB"),
+            (ByteUnit::Mebibyte(1) + ByteUnit::Kibibyte(512), "1536KiB"),
+            (ByteUnit::Gigabyte(3), "3GB"),
+            (ByteUnit::Exbibyte(2), "2EiB"),
+            (ByteUnit::Byte(1025), "1025B"),
+            (ByteUnit::Byte(0), "0B"),
+            (ByteUnit::max_value(), "18446744073709551615B"),
+        ];
+
+        for (value, string) in cases {
+            assert_ser_tokens(&AsStr(value), &[Token::Str(string)]);
+
+            let json = serde_json::to_string(&AsStr(value)).unwrap();
+            let mut de = serde_json::Deserializer::from_str(&json);
+            assert_eq!(super::as_str::deserialize(&mut de).unwrap(), value);
+        }
+    }
+
+    struct AsString(ByteUnit);
+
+    impl serde::Serialize for AsString {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            super::string::serialize(&self.0, serializer)
+        }
+    }
+
+    #[test]
+    #[cfg_attr(any(feature = "default-si", feature = "default-iec"), ignore = "expects Auto")]
+    fn test_string() {
+        use serde::de::IntoDeserializer;
+        use serde::de::value::{Error, StrDeserializer, U64Deserializer};
+
+        let cases = [
+            (ByteUnit::Kibibyte(512), "512KiB"),
+            (ByteUnit::Gigabyte(7) + ByteUnit::Mebibyte(58), "7.06GB"),
+            (ByteUnit::Byte(0), "0B"),
+        ];
+
+        for (value, string) in cases {
+            assert_ser_tokens(&AsString(value).compact(), &[Token::Str(string)]);
+            assert_ser_tokens(&AsString(value).readable(), &[Token::Str(string)]);
+        }
+
+        let de: StrDeserializer<'_, Error> = "512 KiB".into_deserializer();
+        assert_eq!(super::string::deserialize(de).unwrap(), ByteUnit::Kibibyte(512));
+
+        let de: StrDeserializer<'_, Error> = "7.06GB".into_deserializer();
+        assert_eq!(super::string::deserialize(de).unwrap(), ByteUnit::Megabyte(7060));
+
+        let de: StrDeserializer<'_, Error> = "12 parsecs".into_deserializer();
+        assert!(super::string::deserialize(de).is_err());
+
+        let de: U64Deserializer<Error> = 1024u64.into_deserializer();
+        assert!(super::string::deserialize(de).is_err());
+    }
+
+    #[test]
+    fn test_unit() {
+        use serde::de::IntoDeserializer;
+        use serde::de::value::{Error, StrDeserializer};
+
+        struct InUnit(ByteUnit, ByteUnit);
+
+        impl serde::Serialize for InUnit {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                super::unit::serialize_in(&self.0, self.1, serializer)
+            }
+        }
+
+        let cases = [
+            (ByteUnit::Mebibyte(512), ByteUnit::MiB, "512 MiB"),
+            (ByteUnit::Kibibyte(1536), ByteUnit::MiB, "1.5 MiB"),
+            (ByteUnit::Gibibyte(4), ByteUnit::MiB, "4096 MiB"),
+            (ByteUnit::Megabyte(3), ByteUnit::kB, "3000 kB"),
+            (ByteUnit::Byte(0), ByteUnit::GiB, "0 GiB"),
+            (ByteUnit::Byte(1), ByteUnit::MiB, "0.0000009536743164063 MiB"),
+            (ByteUnit::Byte(1), ByteUnit::EiB, "0.0000000000000000009 EiB"),
+            (ByteUnit::Byte(1000), ByteUnit::B, "1000 B"),
+            (ByteUnit::Byte(1000), ByteUnit::Byte(3), "1000 B"),
+            (ByteUnit::max_value(), ByteUnit::kB, "18446744073709551.615 kB"),
+        ];
+
+        for (value, unit, string) in cases {
+            assert_ser_tokens(&InUnit(value, unit), &[Token::Str(string)]);
+            let de: StrDeserializer<'_, Error> = string.into_deserializer();
+            assert_eq!(super::unit::mib::deserialize(de).unwrap(), value);
+        }
+
+        for n in (0..u64::MAX).step_by(u64::MAX as usize / 997) {
+            for unit in [ByteUnit::KiB, ByteUnit::MB, ByteUnit::GiB, ByteUnit::EiB] {
+                let json = serde_json::to_string(&InUnit(ByteUnit::from(n), unit)).unwrap();
+                let mut de = serde_json::Deserializer::from_str(&json);
+                assert_eq!(super::unit::kb::deserialize(&mut de).unwrap(), n);
+            }
+        }
+    }
+
+    #[test]
+    fn test_postcard() {
+        use crate::ToByteUnit;
+
+        // `postcard` is compact and `no_std`; `ByteUnit` encodes as a `u64`.
+        let mid = 7.gibibytes() + 920.mebibytes() + 3;
+        for value in [ByteUnit::ZERO, mid, ByteUnit::max_value()] {
+            let (mut buf, mut expected) = ([0u8; 16], [0u8; 16]);
+            let bytes = postcard::to_slice(&value, &mut buf).unwrap();
+            assert_eq!(bytes, postcard::to_slice(&value.as_u64(), &mut expected).unwrap());
+            assert_eq!(postcard::from_bytes::<ByteUnit>(bytes).unwrap(), value);
+        }
+
+        let mut buf = [0u8; 16];
+        let pair = (1.mebibytes(), 512.kibibytes());
+        let bytes = postcard::to_slice(&pair, &mut buf).unwrap();
+        assert_eq!(postcard::from_bytes::<(ByteUnit, ByteUnit)>(bytes).unwrap(), pair);
+        assert!(postcard::from_bytes::<ByteUnit>(&[0x80]).is_err());
+    }
 }
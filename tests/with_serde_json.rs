@@ -3,7 +3,7 @@
 fn str_is_accepted() {
     let input = r#""42 KiB""#;
 
-    let actual = serde_json::from_str::<ubyte::ByteUnit>(&input).unwrap();
+    let actual = serde_json::from_str::<ubyte::ByteUnit>(input).unwrap();
     assert_eq!(actual.as_u64(), 42 * 1024);
 }
 
@@ -12,6 +12,6 @@ fn str_is_accepted() {
 fn u64_bytes_is_accepted() {
     let input = r#"42"#;
 
-    let actual = serde_json::from_str::<ubyte::ByteUnit>(&input).unwrap();
+    let actual = serde_json::from_str::<ubyte::ByteUnit>(input).unwrap();
     assert_eq!(actual, 42);
 }